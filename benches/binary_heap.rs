@@ -0,0 +1,73 @@
+#![feature(test)]
+
+extern crate test;
+
+use serial::{BinaryHeap, DaryHeap};
+use test::{black_box, Bencher};
+
+// A small xorshift, so that every bench pushes the same sequence.
+fn keys(n: usize) -> Vec<u64> {
+    let mut x = 0x2545_f491_4f6c_dd1d_u64;
+    (0..n)
+        .map(|_| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            x
+        })
+        .collect()
+}
+
+macro_rules! heap_benches {
+    ($push:ident, $push_pop:ident, $pop:ident, $heap:ty) => {
+        /// Pushes 10000 elements and pops a tenth of them.
+        #[bench]
+        fn $push(b: &mut Bencher) {
+            let keys = keys(10_000);
+            b.iter(|| {
+                let mut heap = <$heap>::new();
+                for (i, &key) in keys.iter().enumerate() {
+                    heap.push(key);
+                    if i % 10 == 0 {
+                        black_box(heap.pop());
+                    }
+                }
+                heap
+            });
+        }
+
+        /// Pushes and pops in turn on a heap of 10000 elements.
+        #[bench]
+        fn $push_pop(b: &mut Bencher) {
+            let keys = keys(20_000);
+            let mut heap: $heap = keys[..10_000].iter().copied().collect();
+            b.iter(|| {
+                for &key in &keys[10_000..] {
+                    heap.push(key);
+                    black_box(heap.pop());
+                }
+            });
+        }
+
+        /// Pops all of 10000 elements.
+        #[bench]
+        fn $pop(b: &mut Bencher) {
+            let keys = keys(10_000);
+            b.iter(|| {
+                let mut heap: $heap = keys.iter().copied().collect();
+                while let Some(key) = heap.pop() {
+                    black_box(key);
+                }
+            });
+        }
+    };
+}
+
+heap_benches!(
+    bench_push_binary,
+    bench_push_pop_binary,
+    bench_pop_binary,
+    BinaryHeap<u64>
+);
+heap_benches!(bench_push_dary4, bench_push_pop_dary4, bench_pop_dary4, DaryHeap<u64, 4>);
+heap_benches!(bench_push_dary8, bench_push_pop_dary8, bench_pop_dary8, DaryHeap<u64, 8>);
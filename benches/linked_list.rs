@@ -0,0 +1,55 @@
+#![feature(test)]
+
+extern crate test;
+
+use serial::LinkedList;
+use test::{black_box, Bencher};
+
+fn churn(b: &mut Bencher, mut list: LinkedList<u64>) {
+    b.iter(|| {
+        for i in 0..1000 {
+            list.push_back(i);
+        }
+        while let Some(x) = list.pop_front() {
+            black_box(x);
+        }
+    });
+}
+
+#[bench]
+fn bench_push_pop_boxed(b: &mut Bencher) {
+    churn(b, LinkedList::new());
+}
+
+#[bench]
+fn bench_push_pop_pooled(b: &mut Bencher) {
+    churn(b, LinkedList::with_node_pool(1000));
+}
+
+#[bench]
+fn bench_interleaved_boxed(b: &mut Bencher) {
+    let mut list = LinkedList::new();
+    b.iter(|| {
+        for i in 0..1000 {
+            list.push_back(i);
+            if i % 3 == 0 {
+                black_box(list.pop_front());
+            }
+        }
+        list.clear();
+    });
+}
+
+#[bench]
+fn bench_interleaved_pooled(b: &mut Bencher) {
+    let mut list = LinkedList::with_node_pool(1000);
+    b.iter(|| {
+        for i in 0..1000 {
+            list.push_back(i);
+            if i % 3 == 0 {
+                black_box(list.pop_front());
+            }
+        }
+        list.clear();
+    });
+}
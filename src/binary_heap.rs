@@ -1,24 +1,189 @@
 use crate as serial;
+use crate::TryReserveError;
+use std::alloc::{Allocator, Global};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hint;
 use std::mem::{swap, ManuallyDrop};
 use std::ptr;
 
-pub struct BinaryHeap<T> {
-    data: serial::Vec<T>,
+mod compare;
+pub use compare::{Compare, FnComparator, KeyComparator, MaxComparator, MinComparator};
+
+mod drain;
+pub use drain::{Drain, DrainSorted};
+
+mod indexed;
+pub use indexed::IndexedHeap;
+
+mod into_iter;
+pub use into_iter::IntoIterSorted;
+
+mod iter;
+pub use iter::Iter;
+
+mod min_max;
+pub use min_max::MinMaxHeap;
+
+mod peek_mut;
+pub use peek_mut::PeekMut;
+
+/// A priority queue implemented with a binary heap.
+///
+/// The element on top is the greatest one according to the comparator `C`,
+/// which defaults to [`MaxComparator`] (the [`Ord`] of `T`).
+///
+/// This is a [`DaryHeap`] with two children per node.
+pub type BinaryHeap<T, A = Global, C = MaxComparator> = DaryHeap<T, 2, A, C>;
+
+/// A priority queue implemented with a d-ary heap, where every node has
+/// `D` children instead of two.
+///
+/// A wider heap is shallower, so `push` makes fewer comparisons and `pop`
+/// touches fewer cache lines than in a [`BinaryHeap`], since the children of
+/// a node are next to each other. This makes a 4-ary or 8-ary heap a better
+/// fit for push-heavy workloads, at the price of up to `D` comparisons per
+/// level in `pop`.
+///
+/// The element on top is the greatest one according to the comparator `C`,
+/// which defaults to [`MaxComparator`].
+///
+/// ```
+/// let mut heap = serial::DaryHeap::<_, 8>::new();
+/// heap.extend([3, 1, 4, 1, 5]);
+/// assert_eq!(heap.peek(), Some(&5));
+/// assert_eq!(heap.pop(), Some(5));
+/// assert_eq!(heap.pop(), Some(4));
+/// ```
+pub struct DaryHeap<T, const D: usize = 4, A: Allocator = Global, C = MaxComparator> {
+    data: serial::Vec<T, A>,
+    cmp: C,
 }
 
-impl<T: Ord> BinaryHeap<T> {
+impl<T: Ord, const D: usize> DaryHeap<T, D> {
     pub const fn new() -> Self {
         Self {
             data: serial::Vec::new(),
+            cmp: MaxComparator,
         }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
             data: serial::Vec::with_capacity(capacity),
+            cmp: MaxComparator,
+        }
+    }
+}
+
+impl<T: Ord, const D: usize> DaryHeap<T, D, Global, MinComparator> {
+    /// Creates an empty min-heap, with the least element on top.
+    pub const fn new_min() -> Self {
+        Self {
+            data: serial::Vec::new(),
+            cmp: MinComparator,
+        }
+    }
+
+    /// Creates an empty min-heap with at least the specified capacity.
+    pub fn with_capacity_min(capacity: usize) -> Self {
+        Self {
+            data: serial::Vec::with_capacity(capacity),
+            cmp: MinComparator,
+        }
+    }
+}
+
+impl<T, F, const D: usize> DaryHeap<T, D, Global, FnComparator<F>>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    /// Creates an empty heap ordered by the comparison function `f`,
+    /// with the greatest element on top.
+    pub const fn new_by(f: F) -> Self {
+        Self {
+            data: serial::Vec::new(),
+            cmp: FnComparator(f),
+        }
+    }
+
+    /// Creates an empty heap ordered by the comparison function `f`,
+    /// with at least the specified capacity.
+    pub fn with_capacity_by(capacity: usize, f: F) -> Self {
+        Self {
+            data: serial::Vec::with_capacity(capacity),
+            cmp: FnComparator(f),
+        }
+    }
+}
+
+impl<T, K: Ord, F, const D: usize> DaryHeap<T, D, Global, KeyComparator<F>>
+where
+    F: Fn(&T) -> K,
+{
+    /// Creates an empty heap ordered by the key extraction function `f`,
+    /// with the element with the greatest key on top.
+    pub const fn new_by_key(f: F) -> Self {
+        Self {
+            data: serial::Vec::new(),
+            cmp: KeyComparator(f),
+        }
+    }
+
+    /// Creates an empty heap ordered by the key extraction function `f`,
+    /// with at least the specified capacity.
+    pub fn with_capacity_by_key(capacity: usize, f: F) -> Self {
+        Self {
+            data: serial::Vec::with_capacity(capacity),
+            cmp: KeyComparator(f),
+        }
+    }
+}
+
+impl<T: Ord, A: Allocator, const D: usize> DaryHeap<T, D, A> {
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            data: serial::Vec::new_in(alloc),
+            cmp: MaxComparator,
+        }
+    }
+
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            data: serial::Vec::with_capacity_in(capacity, alloc),
+            cmp: MaxComparator,
+        }
+    }
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> DaryHeap<T, D, A, C> {
+    /// Creates an empty heap ordered by `cmp`, whose elements will be
+    /// allocated in `alloc`.
+    pub const fn with_comparator_in(cmp: C, alloc: A) -> Self {
+        Self {
+            data: serial::Vec::new_in(alloc),
+            cmp,
         }
     }
 
+    /// Creates a heap ordered by `cmp` out of the elements of `vec`.
+    ///
+    /// This conversion happens in-place, and has *O*(*n*) time complexity.
+    pub fn from_vec_cmp(vec: serial::Vec<T, A>, cmp: C) -> Self {
+        let mut heap = DaryHeap { data: vec, cmp };
+        heap.rebuild();
+        heap
+    }
+
+    /// Returns a reference to the comparator ordering the heap.
+    pub fn comparator(&self) -> &C {
+        &self.cmp
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
@@ -30,15 +195,74 @@ impl<T: Ord> BinaryHeap<T> {
     pub fn capacity(&self) -> usize {
         self.data.capacity()
     }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.data.reserve_exact(additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.data.shrink_to(min_capacity);
+    }
+}
+
+impl<T, A: Allocator, C, const D: usize> DaryHeap<T, D, A, C> {
+    /// Returns an iterator visiting all values in the underlying vector, in
+    /// arbitrary order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.data)
+    }
+
+    /// Returns the greatest item in the binary heap according to its
+    /// comparator, or `None` if it is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns a slice of all values in the underlying vector, in arbitrary order.
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Consumes the `BinaryHeap` and returns the underlying vector
+    /// in arbitrary order.
+    pub fn into_vec(self) -> serial::Vec<T, A> {
+        self.data
+    }
+
+    /// Clears the binary heap, returning an iterator over the removed elements
+    /// in arbitrary order. If the iterator is dropped before being fully
+    /// consumed, it drops the remaining elements in arbitrary order.
+    ///
+    /// The returned iterator keeps a mutable borrow on the heap to optimize
+    /// its implementation.
+    pub fn drain(&mut self) -> Drain<'_, T, A> {
+        Drain::new(self.data.drain(..))
+    }
+
+    /// Drops all items from the binary heap.
+    pub fn clear(&mut self) {
+        self.drain();
+    }
 }
 
-impl<T: Ord> Default for BinaryHeap<T> {
-    fn default() -> BinaryHeap<T> {
-        BinaryHeap::new()
+impl<T, C: Compare<T> + Default, const D: usize> Default for DaryHeap<T, D, Global, C> {
+    fn default() -> DaryHeap<T, D, Global, C> {
+        DaryHeap {
+            data: serial::Vec::new(),
+            cmp: C::default(),
+        }
     }
 }
 
-impl<T: Ord> BinaryHeap<T> {
+impl<T, A: Allocator, C: Compare<T>, const D: usize> DaryHeap<T, D, A, C> {
     pub fn push(&mut self, item: T) {
         let old_len = self.len();
         self.data.push(item);
@@ -47,6 +271,91 @@ impl<T: Ord> BinaryHeap<T> {
         unsafe { self.sift_up(0, old_len) };
     }
 
+    /// Pushes an item onto the binary heap, returning an error
+    /// instead of panicking or aborting if the allocation fails.
+    pub fn try_push(&mut self, item: T) -> Result<(), TryReserveError> {
+        let old_len = self.len();
+        self.data.try_push(item)?;
+        // SAFETY: Since we pushed a new item it means that
+        //  old_len = self.len() - 1 < self.len()
+        unsafe { self.sift_up(0, old_len) };
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the greatest item in the binary heap, or
+    /// `None` if it is empty.
+    ///
+    /// If the item is modified through the returned `PeekMut`, the heap is
+    /// restored when it is dropped, which takes *O*(log(*n*)) time.
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, A, C, D>> {
+        if self.is_empty() {
+            None
+        } else {
+            // SAFETY: the heap is not empty.
+            Some(unsafe { PeekMut::new(self) })
+        }
+    }
+
+    /// Moves all the elements of `other` into `self`, leaving `other` empty.
+    ///
+    /// This rebuilds the heap in *O*(*n* + *m*) time or pushes the elements
+    /// of the smaller heap one by one, whichever should be faster.
+    pub fn append(&mut self, other: &mut Self) {
+        // Only the storage is swapped, each heap keeps its own comparator.
+        // The elements taken over from `other` were ordered by its comparator,
+        // so none of them can be trusted to already form a heap.
+        let start = if self.len() < other.len() {
+            swap(&mut self.data, &mut other.data);
+            0
+        } else {
+            self.data.len()
+        };
+
+        self.data.append(&mut other.data);
+
+        self.rebuild_tail(start);
+    }
+
+    /// Clears the binary heap, returning an iterator over the removed elements
+    /// in heap order. If the iterator is dropped before being fully consumed,
+    /// it drops the remaining elements in heap order.
+    ///
+    /// Each element is popped lazily, so this takes *O*(log(*n*)) time per element.
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, T, A, C, D> {
+        DrainSorted::new(self)
+    }
+
+    /// Returns an iterator which retrieves elements in heap order.
+    /// This method consumes the original heap.
+    pub fn into_iter_sorted(self) -> IntoIterSorted<T, A, C, D> {
+        IntoIterSorted::new(self)
+    }
+
+    /// Consumes the `BinaryHeap` and returns a vector in sorted
+    /// (ascending) order according to its comparator.
+    ///
+    /// This is an in-place heapsort, so it takes *O*(*n* \* log(*n*)) time
+    /// and doesn't allocate.
+    pub fn into_sorted_vec(mut self) -> serial::Vec<T, A> {
+        let mut end = self.len();
+        while end > 1 {
+            end -= 1;
+            // SAFETY: `end` goes from `self.len() - 1` to 1 (both included),
+            //  so it's always a valid index to access.
+            //  It is safe to access index 0 (i.e. `ptr`), because
+            //  1 <= end < self.len(), which means self.len() >= 2.
+            unsafe {
+                let ptr = self.data.as_mut_ptr();
+                ptr::swap(ptr, ptr.add(end));
+            }
+            // SAFETY: `end` goes from `self.len() - 1` to 1 (both included) so:
+            //  0 < 1 <= end <= self.len() - 1 < self.len()
+            //  Which means 0 < end and end < self.len().
+            unsafe { self.sift_down_range(0, end) };
+        }
+        self.into_vec()
+    }
+
     pub fn pop(&mut self) -> Option<T> {
         self.data.pop().map(|mut item| {
             if !self.is_empty() {
@@ -59,7 +368,80 @@ impl<T: Ord> BinaryHeap<T> {
     }
 }
 
-impl<T: Ord> BinaryHeap<T> {
+impl<T: Clone, A: Allocator + Clone, C: Clone, const D: usize> Clone for DaryHeap<T, D, A, C> {
+    fn clone(&self) -> Self {
+        DaryHeap {
+            data: self.data.clone(),
+            cmp: self.cmp.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.data.clone_from(&source.data);
+        self.cmp.clone_from(&source.cmp);
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator, C, const D: usize> fmt::Debug for DaryHeap<T, D, A, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.data.iter()).finish()
+    }
+}
+
+impl<T, A: Allocator, C, const D: usize> From<DaryHeap<T, D, A, C>> for serial::Vec<T, A> {
+    fn from(heap: DaryHeap<T, D, A, C>) -> serial::Vec<T, A> {
+        heap.data
+    }
+}
+
+impl<T: Ord, A: Allocator, const D: usize> From<serial::Vec<T, A>> for DaryHeap<T, D, A> {
+    /// Converts a `Vec<T>` into a `DaryHeap<T, D>`.
+    ///
+    /// This conversion happens in-place, and has *O*(*n*) time complexity.
+    fn from(vec: serial::Vec<T, A>) -> DaryHeap<T, D, A> {
+        DaryHeap::from_vec_cmp(vec, MaxComparator)
+    }
+}
+
+impl<T: Ord, const N: usize, const D: usize> From<[T; N]> for DaryHeap<T, D> {
+    fn from(arr: [T; N]) -> Self {
+        Self::from_iter(arr)
+    }
+}
+
+impl<T: Ord, const D: usize> FromIterator<T> for DaryHeap<T, D> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        DaryHeap::from(iter.into_iter().collect::<serial::Vec<_>>())
+    }
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> Extend<T> for DaryHeap<T, D, A, C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.reserve(lower);
+        iter.for_each(move |element| self.push(element));
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator, C: Compare<T>, const D: usize> Extend<&'a T>
+    for DaryHeap<T, D, A, C>
+{
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a, T, A: Allocator, C, const D: usize> IntoIterator for &'a DaryHeap<T, D, A, C> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> DaryHeap<T, D, A, C> {
     // The implementations of sift_up and sift_down use unsafe blocks in
     // order to move an element out of the vector (leaving behind a
     // hole), shift along the others and move the removed element back into the
@@ -68,23 +450,48 @@ impl<T: Ord> BinaryHeap<T> {
     // the hole is filled back at the end of its scope, even on panic.
     // Using a hole reduces the constant factor compared to using swaps,
     // which involves twice as many moves.
+    //
+    // Every node has `D` children: the children of `pos` are
+    // `D * pos + 1..=D * pos + D`.
+
+    // Checked when the sifting functions are instantiated.
+    const ARITY: usize = {
+        assert!(D >= 2, "a d-ary heap needs at least two children per node");
+        D
+    };
 
     /// # Safety
     ///
     /// The caller must guarantee that `pos < self.len()`.
     unsafe fn sift_up(&mut self, start: usize, pos: usize) -> usize {
+        // SAFETY: The caller guarantees that pos < self.len()
+        unsafe { self.sift_up_with(start, pos, ()) }
+    }
+
+    /// Like `sift_up`, but tells `positions` where every element it moves ends up.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `pos < self.len()`.
+    unsafe fn sift_up_with<P: Positions<T>>(
+        &mut self,
+        start: usize,
+        pos: usize,
+        positions: P,
+    ) -> usize {
         // Take out the value at `pos` and create a hole.
         // SAFETY: The caller guarantees that pos < self.len()
-        let mut hole = unsafe { Hole::new(&mut self.data, pos) };
+        let mut hole = unsafe { Hole::with_positions(&mut self.data, pos, positions) };
+        let cmp = &self.cmp;
 
         while hole.pos() > start {
-            let parent = (hole.pos() - 1) / 2;
+            let parent = (hole.pos() - 1) / Self::ARITY;
 
             // SAFETY: hole.pos() > start >= 0, which means hole.pos() > 0
             //  and so hole.pos() - 1 can't underflow.
             //  This guarantees that parent < hole.pos() so
             //  it's a valid index and also != hole.pos().
-            if hole.element() <= unsafe { hole.get(parent) } {
+            if cmp.compare(hole.element(), unsafe { hole.get(parent) }) <= Ordering::Equal {
                 break;
             }
 
@@ -95,6 +502,79 @@ impl<T: Ord> BinaryHeap<T> {
         hole.pos()
     }
 
+    /// Take an element at `pos` and move it down the heap,
+    /// while its children are larger.
+    ///
+    /// Returns the new position of the element.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `pos < end <= self.len()`.
+    unsafe fn sift_down_range(&mut self, pos: usize, end: usize) -> usize {
+        // SAFETY: The caller guarantees that pos < end <= self.len().
+        unsafe { self.sift_down_range_with(pos, end, ()) }
+    }
+
+    /// Like `sift_down_range`, but tells `positions` where every element it moves ends up.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `pos < end <= self.len()`.
+    unsafe fn sift_down_range_with<P: Positions<T>>(
+        &mut self,
+        pos: usize,
+        end: usize,
+        positions: P,
+    ) -> usize {
+        // SAFETY: The caller guarantees that pos < end <= self.len().
+        let mut hole = unsafe { Hole::with_positions(&mut self.data, pos, positions) };
+        let cmp = &self.cmp;
+        let mut child = Self::ARITY * hole.pos() + 1;
+
+        // Loop invariant: child == D * hole.pos() + 1.
+        while child <= end.saturating_sub(Self::ARITY) {
+            // compare with the greatest of the D children
+            // SAFETY: child + D - 1 < end <= self.len(), so all the children
+            //  are valid indexes, and none of them is hole.pos().
+            // FIXME: D * hole.pos() + D could overflow if T is a ZST
+            child = unsafe { greatest_child(cmp, &hole, child, Self::ARITY) };
+
+            // if we are already in order, stop.
+            // SAFETY: child is one of the children of hole.pos().
+            if cmp.compare(hole.element(), unsafe { hole.get(child) }) >= Ordering::Equal {
+                return hole.pos();
+            }
+
+            // SAFETY: same as above.
+            unsafe { hole.move_to(child) };
+            child = Self::ARITY * hole.pos() + 1;
+        }
+
+        if child < end {
+            // The last parent can have fewer than D children.
+            // SAFETY: child < end and end - child < D.
+            child = unsafe { greatest_child(cmp, &hole, child, end - child) };
+
+            // SAFETY: child is one of the children of hole.pos().
+            if cmp.compare(hole.element(), unsafe { hole.get(child) }) < Ordering::Equal {
+                // SAFETY: same as above.
+                unsafe { hole.move_to(child) };
+            }
+        }
+
+        hole.pos()
+    }
+
+    /// # Safety
+    ///
+    /// The caller must guarantee that `pos < self.len()`.
+    unsafe fn sift_down(&mut self, pos: usize) -> usize {
+        let len = self.len();
+        // SAFETY: pos < len is guaranteed by the caller and
+        //  obviously len = self.len() <= self.len().
+        unsafe { self.sift_down_range(pos, len) }
+    }
+
     /// Take an element at `pos` and move it all the way down the heap,
     /// then sift it up to its position.
     ///
@@ -110,26 +590,25 @@ impl<T: Ord> BinaryHeap<T> {
 
         // SAFETY: The caller guarantees that pos < self.len().
         let mut hole = unsafe { Hole::new(&mut self.data, pos) };
-        let mut child = 2 * hole.pos() + 1;
-
-        // Loop invariant: child == 2 * hole.pos() + 1.
-        while child <= end.saturating_sub(2) {
-            // SAFETY: child < end - 1 < self.len() and
-            //  child + 1 < end <= self.len(), so they're valid indexes.
-            //  child == 2 * hole.pos() + 1 != hole.pos() and
-            //  child + 1 == 2 * hole.pos() + 2 != hole.pos().
-            // FIXME: 2 * hole.pos() + 1 or 2 * hole.pos() + 2 could overflow
-            //  if T is a ZST
-            child += unsafe { hole.get(child) <= hole.get(child + 1) } as usize;
+        let cmp = &self.cmp;
+        let mut child = Self::ARITY * hole.pos() + 1;
 
-            // SAFETY: Same as above
+        // Loop invariant: child == D * hole.pos() + 1.
+        while child <= end.saturating_sub(Self::ARITY) {
+            // SAFETY: child + D - 1 < end <= self.len(), so all the children
+            //  are valid indexes, and none of them is hole.pos().
+            // FIXME: D * hole.pos() + D could overflow if T is a ZST
+            child = unsafe { greatest_child(cmp, &hole, child, Self::ARITY) };
+
+            // SAFETY: child is one of the children of hole.pos().
             unsafe { hole.move_to(child) };
-            child = 2 * hole.pos() + 1;
+            child = Self::ARITY * hole.pos() + 1;
         }
 
-        if child == end - 1 {
-            // SAFETY: child == end - 1 < self.len(), so it's a valid index
-            //  and child == 2 * hole.pos() + 1 != hole.pos().
+        if child < end {
+            // SAFETY: child < end and end - child < D.
+            child = unsafe { greatest_child(cmp, &hole, child, end - child) };
+            // SAFETY: child is one of the children of hole.pos().
             unsafe { hole.move_to(child) };
         }
         pos = hole.pos();
@@ -141,10 +620,102 @@ impl<T: Ord> BinaryHeap<T> {
     }
 }
 
-struct Hole<'a, T> {
+impl<T, A: Allocator, C: Compare<T>, const D: usize> DaryHeap<T, D, A, C> {
+    /// Restores the heap property of the whole vector, bottom-up in *O*(*n*) time.
+    fn rebuild(&mut self) {
+        // Start from the parent of the last element.
+        let mut n = self.len().saturating_sub(1).div_ceil(Self::ARITY);
+        while n > 0 {
+            n -= 1;
+            // SAFETY: n starts from (self.len() - 1).div_ceil(D) and goes
+            //  down to 0. The only case when !(n < self.len()) is if
+            //  self.len() == 0, but then n starts from 0 and the loop is skipped.
+            unsafe { self.sift_down(n) };
+        }
+    }
+
+    /// Restores the heap property after the elements from `start` on were
+    /// added to the end of the vector, either with a full rebuild or by
+    /// sifting each of them up, whichever is cheaper.
+    fn rebuild_tail(&mut self, start: usize) {
+        if start == self.len() {
+            return;
+        }
+
+        let tail_len = self.len() - start;
+
+        #[inline(always)]
+        fn log2_fast(x: usize) -> usize {
+            (usize::BITS - x.leading_zeros() - 1) as usize
+        }
+
+        // `rebuild` takes O(self.len()) operations
+        // and about 2 * self.len() comparisons in the worst case
+        // while repeating `sift_up` takes O(tail_len * log(start)) operations
+        // and about 1 * tail_len * log_2(start) comparisons in the worst case,
+        // assuming start >= tail_len. For larger heaps, the crossover point
+        // no longer follows this reasoning and was determined empirically.
+        let better_to_rebuild = if start < tail_len {
+            true
+        } else if self.len() <= 2048 {
+            2 * self.len() < tail_len * log2_fast(start)
+        } else {
+            2 * self.len() < tail_len * 11
+        };
+
+        if better_to_rebuild {
+            self.rebuild();
+        } else {
+            for i in start..self.len() {
+                // SAFETY: The index `i` is always less than self.len().
+                unsafe { self.sift_up(0, i) };
+            }
+        }
+    }
+}
+
+/// Returns the greatest of the `count` children of the hole starting at `first`.
+///
+/// # Safety
+///
+/// The caller must guarantee that `count > 0` and that `first..first + count`
+/// are valid indexes in the slice, none of them equal to `hole.pos()`.
+#[inline(always)]
+unsafe fn greatest_child<T, C: Compare<T>, P: Positions<T>>(
+    cmp: &C,
+    hole: &Hole<'_, T, P>,
+    first: usize,
+    count: usize,
+) -> usize {
+    let mut greatest = first;
+    for i in 1..count {
+        // SAFETY: The caller guarantees that both are valid indexes
+        //  and != hole.pos().
+        let le = unsafe { cmp.compare(hole.get(greatest), hole.get(first + i)) }.is_le();
+        // Which child is greater can't be predicted, so a branch would be
+        // mispredicted half of the time. With two children adding the
+        // comparison result works as well, but for more children LLVM turns
+        // that back into a branch.
+        greatest = hint::select_unpredictable(le, first + i, greatest);
+    }
+    greatest
+}
+
+/// Gets told the new position of every element a `Hole` moves.
+trait Positions<T> {
+    fn set(&mut self, element: &T, pos: usize);
+}
+
+impl<T> Positions<T> for () {
+    #[inline(always)]
+    fn set(&mut self, _element: &T, _pos: usize) {}
+}
+
+struct Hole<'a, T, P: Positions<T> = ()> {
     data: &'a mut [T],
     elem: ManuallyDrop<T>,
     pos: usize,
+    positions: P,
 }
 
 impl<'a, T> Hole<'a, T> {
@@ -152,6 +723,15 @@ impl<'a, T> Hole<'a, T> {
     ///
     /// Unsafe because pos must be within the data slice.
     unsafe fn new(data: &'a mut [T], pos: usize) -> Self {
+        unsafe { Hole::with_positions(data, pos, ()) }
+    }
+}
+
+impl<'a, T, P: Positions<T>> Hole<'a, T, P> {
+    /// Create a new `Hole` at index `pos` that reports the moved elements to `positions`.
+    ///
+    /// Unsafe because pos must be within the data slice.
+    unsafe fn with_positions(data: &'a mut [T], pos: usize, positions: P) -> Self {
         debug_assert!(pos < data.len());
         // SAFE: pos should be inside the slice
         let elt = unsafe { ptr::read(data.get_unchecked(pos)) };
@@ -159,6 +739,7 @@ impl<'a, T> Hole<'a, T> {
             data,
             elem: ManuallyDrop::new(elt),
             pos,
+            positions,
         }
     }
 
@@ -191,17 +772,32 @@ impl<'a, T> Hole<'a, T> {
             let index_ptr: *const _ = ptr.add(index);
             let hole_ptr = ptr.add(self.pos);
             ptr::copy_nonoverlapping(index_ptr, hole_ptr, 1);
+            self.positions.set(&*hole_ptr, self.pos);
         }
         self.pos = index;
     }
+
+    /// Swaps the element removed with the element at `index`, leaving the hole in place.
+    ///
+    /// Unsafe because index must be within the data slice and not equal to pos.
+    unsafe fn swap_element(&mut self, index: usize) {
+        debug_assert!(index != self.pos);
+        debug_assert!(index < self.data.len());
+        unsafe {
+            let index_ptr = self.data.get_unchecked_mut(index);
+            swap(&mut *self.elem, index_ptr);
+            self.positions.set(index_ptr, index);
+        }
+    }
 }
 
-impl<T> Drop for Hole<'_, T> {
+impl<T, P: Positions<T>> Drop for Hole<'_, T, P> {
     fn drop(&mut self) {
         // fill the hole again
         unsafe {
             let pos = self.pos;
             ptr::copy_nonoverlapping(&*self.elem, self.data.get_unchecked_mut(pos), 1);
         }
+        self.positions.set(&self.elem, self.pos);
     }
 }
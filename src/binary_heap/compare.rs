@@ -0,0 +1,54 @@
+use std::cmp::Ordering;
+
+/// An ordering for the elements of a [`BinaryHeap`](super::BinaryHeap).
+///
+/// The heap keeps the greatest element according to `compare` on top.
+pub trait Compare<T: ?Sized> {
+    fn compare(&self, l: &T, r: &T) -> Ordering;
+}
+
+/// Orders elements by their [`Ord`] implementation, making a max-heap.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct MaxComparator;
+
+impl<T: Ord> Compare<T> for MaxComparator {
+    fn compare(&self, l: &T, r: &T) -> Ordering {
+        l.cmp(r)
+    }
+}
+
+/// Orders elements by the reverse of their [`Ord`] implementation, making a min-heap.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct MinComparator;
+
+impl<T: Ord> Compare<T> for MinComparator {
+    fn compare(&self, l: &T, r: &T) -> Ordering {
+        r.cmp(l)
+    }
+}
+
+/// Orders elements with a comparison function.
+#[derive(Clone, Copy, Debug)]
+pub struct FnComparator<F>(pub F);
+
+impl<T, F> Compare<T> for FnComparator<F>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn compare(&self, l: &T, r: &T) -> Ordering {
+        (self.0)(l, r)
+    }
+}
+
+/// Orders elements by the [`Ord`] implementation of a key extracted from them.
+#[derive(Clone, Copy, Debug)]
+pub struct KeyComparator<F>(pub F);
+
+impl<K: Ord, T, F> Compare<T> for KeyComparator<F>
+where
+    F: Fn(&T) -> K,
+{
+    fn compare(&self, l: &T, r: &T) -> Ordering {
+        (self.0)(l).cmp(&(self.0)(r))
+    }
+}
@@ -0,0 +1,126 @@
+use super::{Compare, DaryHeap, MaxComparator};
+use crate::vec;
+use std::alloc::{Allocator, Global};
+use std::fmt;
+use std::iter::FusedIterator;
+use std::mem;
+
+/// A draining iterator over the elements of a `BinaryHeap`.
+///
+/// This `struct` is created by [`BinaryHeap::drain()`](DaryHeap::drain). The elements are
+/// yielded in arbitrary order.
+pub struct Drain<'a, T: 'a, A: Allocator = Global> {
+    iter: vec::Drain<'a, T, A>,
+}
+
+impl<'a, T, A: Allocator> Drain<'a, T, A> {
+    pub(super) fn new(iter: vec::Drain<'a, T, A>) -> Self {
+        Self { iter }
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.iter.allocator()
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Drain<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.iter.as_slice()).finish()
+    }
+}
+
+impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for Drain<'_, T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> FusedIterator for Drain<'_, T, A> {}
+
+/// A draining iterator over the elements of a `BinaryHeap`.
+///
+/// This `struct` is created by [`BinaryHeap::drain_sorted()`](DaryHeap::drain_sorted). The elements
+/// are yielded in heap order, and the ones left over are dropped along with it.
+pub struct DrainSorted<
+    'a,
+    T,
+    A: Allocator = Global,
+    C: Compare<T> = MaxComparator,
+    const D: usize = 2,
+> {
+    inner: &'a mut DaryHeap<T, D, A, C>,
+}
+
+impl<'a, T, A: Allocator, C: Compare<T>, const D: usize> DrainSorted<'a, T, A, C, D> {
+    pub(super) fn new(inner: &'a mut DaryHeap<T, D, A, C>) -> Self {
+        Self { inner }
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.inner.allocator()
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator, C: Compare<T>, const D: usize> fmt::Debug
+    for DrainSorted<'_, T, A, C, D>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DrainSorted").field(&self.inner).finish()
+    }
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> Drop for DrainSorted<'_, T, A, C, D> {
+    /// Removes heap elements in heap order.
+    fn drop(&mut self) {
+        struct DropGuard<'r, 'a, T, A: Allocator, C: Compare<T>, const D: usize>(
+            &'r mut DrainSorted<'a, T, A, C, D>,
+        );
+
+        impl<T, A: Allocator, C: Compare<T>, const D: usize> Drop for DropGuard<'_, '_, T, A, C, D> {
+            fn drop(&mut self) {
+                while self.0.inner.pop().is_some() {}
+            }
+        }
+
+        while let Some(item) = self.inner.pop() {
+            // Keep draining if dropping an element panics.
+            let guard = DropGuard(self);
+            drop(item);
+            mem::forget(guard);
+        }
+    }
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> Iterator for DrainSorted<'_, T, A, C, D> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact = self.inner.len();
+        (exact, Some(exact))
+    }
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> ExactSizeIterator
+    for DrainSorted<'_, T, A, C, D>
+{
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> FusedIterator for DrainSorted<'_, T, A, C, D> {}
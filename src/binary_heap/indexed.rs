@@ -0,0 +1,248 @@
+use super::{BinaryHeap, Compare, MaxComparator, MinComparator, Positions};
+use std::alloc::Global;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::mem::swap;
+
+/// A priority queue of keys, where the priority of a queued key can be
+/// changed or the key removed in *O*(log(*n*)) time.
+///
+/// Every key is in the queue at most once. A map from each key to its
+/// position in the heap is kept up to date while the entries are sifted.
+///
+/// The key on top is the one with the greatest priority according to the
+/// comparator `C`, which defaults to [`MaxComparator`].
+pub struct IndexedHeap<K, P, C = MaxComparator> {
+    // The entries are sifted by the `BinaryHeap` code, which reports every
+    // move to `positions`.
+    heap: BinaryHeap<(K, P), Global, ByPriority<C>>,
+    positions: HashMap<K, usize>,
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> IndexedHeap<K, P> {
+    /// Creates an empty queue with the greatest priority on top.
+    pub fn new() -> Self {
+        Self::with_comparator(MaxComparator)
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> IndexedHeap<K, P, MinComparator> {
+    /// Creates an empty queue with the least priority on top.
+    pub fn new_min() -> Self {
+        Self::with_comparator(MinComparator)
+    }
+}
+
+impl<K: Hash + Eq + Clone, P, C: Compare<P>> IndexedHeap<K, P, C> {
+    /// Creates an empty queue ordered by `cmp`.
+    pub fn with_comparator(cmp: C) -> Self {
+        Self {
+            heap: BinaryHeap::with_comparator_in(ByPriority(cmp), Global),
+            positions: HashMap::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if `key` is in the queue.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.positions.contains_key(key)
+    }
+
+    /// Returns the priority of `key`, or `None` if it is not in the queue.
+    pub fn priority<Q>(&self, key: &Q) -> Option<&P>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.positions.get(key).map(|&pos| &self.heap.data[pos].1)
+    }
+
+    /// Returns the key with the greatest priority and its priority,
+    /// or `None` if the queue is empty.
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.heap.peek().map(|(key, priority)| (key, priority))
+    }
+
+    /// Returns an iterator visiting all keys and their priorities in arbitrary order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&K, &P)> {
+        self.heap.iter().map(|(key, priority)| (key, priority))
+    }
+
+    /// Pushes `key` with `priority` onto the queue.
+    ///
+    /// If `key` is already queued, its priority is changed instead
+    /// and the old priority is returned.
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        if let Some(&pos) = self.positions.get(&key) {
+            return Some(self.change_priority_at(pos, priority));
+        }
+
+        let old_len = self.len();
+        self.positions.insert(key.clone(), old_len);
+        self.heap.data.push((key, priority));
+        let positions = PositionMap(&mut self.positions);
+        // SAFETY: Since we pushed a new item it means that
+        //  old_len = self.len() - 1 < self.len()
+        unsafe { self.heap.sift_up_with(0, old_len, positions) };
+        None
+    }
+
+    /// Removes the key with the greatest priority and returns it along
+    /// with its priority, or `None` if the queue is empty.
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        self.heap.data.pop().map(|mut item| {
+            if !self.is_empty() {
+                swap(&mut item, &mut self.heap.data[0]);
+            }
+            // Forget the key before sifting, so that the map doesn't keep it
+            // if the comparator panics.
+            self.positions.remove(&item.0);
+            if !self.is_empty() {
+                let len = self.len();
+                let positions = PositionMap(&mut self.positions);
+                // SAFETY: !self.is_empty() means that 0 < self.len()
+                unsafe { self.heap.sift_down_range_with(0, len, positions) };
+            }
+            item
+        })
+    }
+
+    /// Changes the priority of `key`, returning the old priority,
+    /// or `None` if `key` is not in the queue.
+    ///
+    /// This operation should compute in *O*(log(*n*)) time.
+    pub fn change_priority<Q>(&mut self, key: &Q, priority: P) -> Option<P>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let pos = *self.positions.get(key)?;
+        Some(self.change_priority_at(pos, priority))
+    }
+
+    /// Removes `key` from the queue, returning its priority,
+    /// or `None` if `key` is not in the queue.
+    ///
+    /// This operation should compute in *O*(log(*n*)) time.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<P>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let pos = self.positions.remove(key)?;
+        let (_, priority) = self.heap.data.swap_remove(pos);
+        if pos < self.len() {
+            // The last entry was moved into `pos`, so fix up its position
+            // and restore the heap property around it.
+            // SAFETY: pos < self.len()
+            unsafe { self.sift_up_or_down(pos) };
+        }
+        Some(priority)
+    }
+
+    /// Removes all keys from the queue.
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.heap.clear();
+    }
+
+    fn change_priority_at(&mut self, pos: usize, mut priority: P) -> P {
+        swap(&mut self.heap.data[pos].1, &mut priority);
+        // SAFETY: pos comes from the position map, so pos < self.len()
+        unsafe { self.sift_up_or_down(pos) };
+        priority
+    }
+
+    /// Moves the entry at `pos` to where it belongs after its priority changed,
+    /// updating its position even if it stays put.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `pos < self.len()`.
+    unsafe fn sift_up_or_down(&mut self, pos: usize) {
+        let len = self.len();
+        // SAFETY: The caller guarantees that pos < self.len(),
+        //  and sift_up_with returns a valid position.
+        unsafe {
+            let positions = PositionMap(&mut self.positions);
+            let new_pos = self.heap.sift_up_with(0, pos, positions);
+            if new_pos == pos {
+                let positions = PositionMap(&mut self.positions);
+                self.heap.sift_down_range_with(pos, len, positions);
+            }
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> Default for IndexedHeap<K, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone, P: Clone, C: Clone> Clone for IndexedHeap<K, P, C> {
+    fn clone(&self) -> Self {
+        Self {
+            heap: self.heap.clone(),
+            positions: self.positions.clone(),
+        }
+    }
+}
+
+impl<K: fmt::Debug, P: fmt::Debug, C> fmt::Debug for IndexedHeap<K, P, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.heap.iter().map(|(key, priority)| (key, priority)))
+            .finish()
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> FromIterator<(K, P)> for IndexedHeap<K, P> {
+    fn from_iter<I: IntoIterator<Item = (K, P)>>(iter: I) -> Self {
+        let mut heap = Self::new();
+        heap.extend(iter);
+        heap
+    }
+}
+
+impl<K: Hash + Eq + Clone, P, C: Compare<P>> Extend<(K, P)> for IndexedHeap<K, P, C> {
+    fn extend<I: IntoIterator<Item = (K, P)>>(&mut self, iter: I) {
+        iter.into_iter()
+            .for_each(move |(key, priority)| drop(self.push(key, priority)));
+    }
+}
+
+/// Records the positions of the entries moved by a `Hole`.
+struct PositionMap<'a, K>(&'a mut HashMap<K, usize>);
+
+impl<K: Hash + Eq, P> Positions<(K, P)> for PositionMap<'_, K> {
+    fn set(&mut self, (key, _): &(K, P), pos: usize) {
+        if let Some(position) = self.0.get_mut(key) {
+            *position = pos;
+        }
+    }
+}
+
+/// Orders the entries of an `IndexedHeap` by their priority only.
+#[derive(Clone)]
+struct ByPriority<C>(C);
+
+impl<K, P, C: Compare<P>> Compare<(K, P)> for ByPriority<C> {
+    fn compare(&self, l: &(K, P), r: &(K, P)) -> Ordering {
+        self.0.compare(&l.1, &r.1)
+    }
+}
@@ -0,0 +1,57 @@
+use super::{Compare, DaryHeap, MaxComparator};
+use std::alloc::{Allocator, Global};
+use std::fmt;
+use std::iter::FusedIterator;
+
+/// An owning iterator over the elements of a `BinaryHeap`, in heap order.
+///
+/// This `struct` is created by [`BinaryHeap::into_iter_sorted()`](DaryHeap::into_iter_sorted).
+pub struct IntoIterSorted<T, A: Allocator = Global, C = MaxComparator, const D: usize = 2> {
+    inner: DaryHeap<T, D, A, C>,
+}
+
+impl<T, A: Allocator, C, const D: usize> IntoIterSorted<T, A, C, D> {
+    pub(super) fn new(inner: DaryHeap<T, D, A, C>) -> Self {
+        Self { inner }
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.inner.data.allocator()
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone, C: Clone, const D: usize> Clone
+    for IntoIterSorted<T, A, C, D>
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator, C, const D: usize> fmt::Debug for IntoIterSorted<T, A, C, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIterSorted").field(&self.inner).finish()
+    }
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> Iterator for IntoIterSorted<T, A, C, D> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact = self.inner.len();
+        (exact, Some(exact))
+    }
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> ExactSizeIterator
+    for IntoIterSorted<T, A, C, D>
+{
+}
+
+impl<T, A: Allocator, C: Compare<T>, const D: usize> FusedIterator for IntoIterSorted<T, A, C, D> {}
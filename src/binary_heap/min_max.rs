@@ -0,0 +1,339 @@
+use super::{Hole, Iter};
+use crate as serial;
+use std::cmp::Ordering;
+use std::fmt;
+use std::mem::swap;
+
+/// A double-ended priority queue implemented with a min-max heap.
+///
+/// The elements on even levels of the heap are smaller than all of their
+/// descendants and the elements on odd levels are greater, so both the
+/// smallest and the greatest element can be peeked at in *O*(1) time and
+/// popped in *O*(log(*n*)) time.
+///
+/// ```
+/// let mut heap = serial::MinMaxHeap::new();
+/// heap.extend([3, 1, 4, 1, 5]);
+/// assert_eq!(heap.peek_min(), Some(&1));
+/// assert_eq!(heap.peek_max(), Some(&5));
+/// assert_eq!(heap.push_pop_max(2), 5);
+/// assert_eq!(heap.pop_max(), Some(4));
+/// assert_eq!(heap.pop_min(), Some(1));
+/// ```
+pub struct MinMaxHeap<T> {
+    data: serial::Vec<T>,
+}
+
+impl<T: Ord> MinMaxHeap<T> {
+    pub const fn new() -> Self {
+        Self {
+            data: serial::Vec::new(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            data: serial::Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Pushes an item onto the heap.
+    pub fn push(&mut self, item: T) {
+        let old_len = self.len();
+        self.data.push(item);
+        // SAFETY: Since we pushed a new item it means that
+        //  old_len = self.len() - 1 < self.len()
+        unsafe { self.sift_up(old_len) };
+    }
+
+    /// Removes the smallest item from the heap and returns it, or `None` if it is empty.
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.pop_at(0)
+    }
+
+    /// Removes the greatest item from the heap and returns it, or `None` if it is empty.
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.pop_at(self.max_index()?)
+    }
+
+    /// Returns the smallest item in the heap, or `None` if it is empty.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns the greatest item in the heap, or `None` if it is empty.
+    pub fn peek_max(&self) -> Option<&T> {
+        self.max_index().map(|pos| &self.data[pos])
+    }
+
+    /// Pushes an item onto the heap, then removes the greatest item and returns it.
+    ///
+    /// This is faster than a `push` followed by a `pop_max`, and returns `item`
+    /// right away if it is not smaller than the greatest item.
+    pub fn push_pop_max(&mut self, mut item: T) -> T {
+        let Some(pos) = self.max_index() else {
+            return item;
+        };
+        if item >= self.data[pos] {
+            return item;
+        }
+
+        swap(&mut item, &mut self.data[pos]);
+        if pos > 0 {
+            if self.data[pos] < self.data[0] {
+                self.data.swap(0, pos);
+            }
+            // SAFETY: pos is the index of the greatest item, so pos < self.len()
+            unsafe { self.sift_down(pos) };
+        }
+        item
+    }
+
+    /// Returns the position of the greatest item, or `None` if the heap is empty.
+    fn max_index(&self) -> Option<usize> {
+        match self.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ => Some(if self.data[1] < self.data[2] { 2 } else { 1 }),
+        }
+    }
+
+    fn pop_at(&mut self, pos: usize) -> Option<T> {
+        self.data.pop().map(|mut item| {
+            if pos < self.len() {
+                swap(&mut item, &mut self.data[pos]);
+                // SAFETY: pos < self.len()
+                unsafe { self.sift_down(pos) };
+            }
+            item
+        })
+    }
+
+    // The sifting functions use `Hole` the same way as `BinaryHeap`. Every
+    // element has to be in order with the ancestors on its own kind of level,
+    // so they mostly compare with grandparents and grandchildren, and use
+    // `ordering` to tell if an element belongs closer to the root: `Less` on
+    // min levels and `Greater` on max levels.
+
+    /// Moves the element at `pos` up to where it belongs.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `pos < self.len()`.
+    unsafe fn sift_up(&mut self, pos: usize) {
+        // SAFETY: The caller guarantees that pos < self.len()
+        let mut hole = unsafe { Hole::new(&mut self.data, pos) };
+        if hole.pos() == 0 {
+            return;
+        }
+
+        let mut ordering = level_ordering(pos);
+        let parent = (pos - 1) / 2;
+        // SAFETY: parent < hole.pos() so it's a valid index and also != hole.pos().
+        if hole.element().cmp(unsafe { hole.get(parent) }) == ordering.reverse() {
+            // The element belongs on the levels of its parent.
+            // SAFETY: Same as above
+            unsafe { hole.move_to(parent) };
+            ordering = ordering.reverse();
+        }
+
+        while hole.pos() > 2 {
+            let grandparent = (hole.pos() - 3) / 4;
+
+            // SAFETY: grandparent < hole.pos() so it's a valid index and also != hole.pos().
+            if hole.element().cmp(unsafe { hole.get(grandparent) }) != ordering {
+                break;
+            }
+
+            // SAFETY: Same as above
+            unsafe { hole.move_to(grandparent) };
+        }
+    }
+
+    /// Moves the element at `pos` down to where it belongs.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that `pos < self.len()`.
+    unsafe fn sift_down(&mut self, pos: usize) {
+        let end = self.len();
+        let ordering = level_ordering(pos);
+
+        // SAFETY: The caller guarantees that pos < self.len().
+        let mut hole = unsafe { Hole::new(&mut self.data, pos) };
+
+        loop {
+            let child = 2 * hole.pos() + 1;
+            if child >= end {
+                break;
+            }
+
+            // Find the first in order among the children and grandchildren.
+            let mut next = child;
+            for other in [
+                child + 1,
+                2 * child + 1,
+                2 * child + 2,
+                2 * child + 3,
+                2 * child + 4,
+            ] {
+                if other >= end {
+                    break;
+                }
+                // SAFETY: other and next are < end and below hole.pos().
+                if unsafe { hole.get(other).cmp(hole.get(next)) } == ordering {
+                    next = other;
+                }
+            }
+
+            // if we are already in order, stop.
+            // SAFETY: next is a descendant of hole.pos() and < end.
+            if unsafe { hole.get(next) }.cmp(hole.element()) != ordering {
+                break;
+            }
+
+            // SAFETY: same as above.
+            unsafe { hole.move_to(next) };
+            if next <= child + 1 {
+                // A child is on the other kind of level, and has no grandchildren to sift past.
+                break;
+            }
+
+            let parent = (next - 1) / 2;
+            // SAFETY: parent is the child the grandchild came from, so it is
+            //  a valid index and != hole.pos().
+            if hole.element().cmp(unsafe { hole.get(parent) }) == ordering.reverse() {
+                // SAFETY: same as above.
+                unsafe { hole.swap_element(parent) };
+            }
+        }
+    }
+}
+
+impl<T> MinMaxHeap<T> {
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Returns an iterator visiting all values in the underlying vector, in
+    /// arbitrary order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(&self.data)
+    }
+
+    /// Returns a slice of all values in the underlying vector, in arbitrary order.
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Consumes the `MinMaxHeap` and returns the underlying vector
+    /// in arbitrary order.
+    pub fn into_vec(self) -> serial::Vec<T> {
+        self.data
+    }
+
+    /// Drops all items from the heap.
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+}
+
+/// Returns the ordering of an element before its descendants on the level of `pos`.
+fn level_ordering(pos: usize) -> Ordering {
+    if (pos + 1).ilog2().is_multiple_of(2) {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+impl<T: Ord> Default for MinMaxHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone> Clone for MinMaxHeap<T> {
+    fn clone(&self) -> Self {
+        MinMaxHeap {
+            data: self.data.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.data.clone_from(&source.data);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MinMaxHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.data.iter()).finish()
+    }
+}
+
+impl<T> From<MinMaxHeap<T>> for serial::Vec<T> {
+    fn from(heap: MinMaxHeap<T>) -> serial::Vec<T> {
+        heap.data
+    }
+}
+
+impl<T: Ord> From<serial::Vec<T>> for MinMaxHeap<T> {
+    /// Converts a `Vec<T>` into a `MinMaxHeap<T>`.
+    ///
+    /// This conversion happens in-place, and has *O*(*n*) time complexity.
+    fn from(vec: serial::Vec<T>) -> MinMaxHeap<T> {
+        let mut heap = MinMaxHeap { data: vec };
+        let mut n = heap.len() / 2;
+        while n > 0 {
+            n -= 1;
+            // SAFETY: n starts from self.len() / 2 and goes down to 0.
+            unsafe { heap.sift_down(n) };
+        }
+        heap
+    }
+}
+
+impl<T: Ord, const N: usize> From<[T; N]> for MinMaxHeap<T> {
+    fn from(arr: [T; N]) -> Self {
+        Self::from_iter(arr)
+    }
+}
+
+impl<T: Ord> FromIterator<T> for MinMaxHeap<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        MinMaxHeap::from(iter.into_iter().collect::<serial::Vec<_>>())
+    }
+}
+
+impl<T: Ord> Extend<T> for MinMaxHeap<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.data.reserve(lower);
+        iter.for_each(move |element| self.push(element));
+    }
+}
+
+impl<'a, T: Copy + Ord + 'a> Extend<&'a T> for MinMaxHeap<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MinMaxHeap<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
@@ -1,16 +1,20 @@
 #![feature(allocator_api)]
 #![feature(dropck_eyepatch)]
+#![feature(slice_range)]
+
+mod macros;
 
 mod try_reserve_error;
 pub use try_reserve_error::{TryReserveError, TryReserveErrorKind};
 
 mod raw_vec;
 
-mod vec;
+pub mod vec;
 pub use vec::Vec;
 
-mod linked_list;
+pub mod linked_list;
+pub use linked_list::intrusive;
 pub use linked_list::LinkedList;
 
-mod binary_heap;
-pub use binary_heap::BinaryHeap;
+pub mod binary_heap;
+pub use binary_heap::{BinaryHeap, DaryHeap, IndexedHeap, MinMaxHeap};
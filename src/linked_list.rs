@@ -1,5 +1,24 @@
+use std::alloc::{Allocator, Global, Layout};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::FusedIterator;
 use std::marker::PhantomData;
-use std::ptr::NonNull;
+use std::mem;
+use std::ptr::{self, NonNull};
+
+use crate::TryReserveError;
+use crate::TryReserveErrorKind::AllocError;
+
+mod cursor;
+pub use cursor::{Cursor, CursorMut};
+
+pub mod intrusive;
+
+mod pool;
+use pool::NodePool;
+
+mod sort;
 
 type Link<T> = Option<NonNull<Node<T>>>;
 
@@ -17,28 +36,44 @@ impl<T> Node<T> {
             element,
         }
     }
-
-    fn into_element(self: Box<Self>) -> T {
-        self.element
-    }
 }
 
-pub struct LinkedList<T> {
+pub struct LinkedList<T, A: Allocator + Clone = Global> {
     head: Link<T>,
     tail: Link<T>,
     len: usize,
-    _pd: PhantomData<Box<Node<T>>>,
+    pool: NodePool<T>,
+    alloc: A,
+    _pd: PhantomData<Box<Node<T>, A>>,
 }
 
+unsafe impl<T: Send, A: Allocator + Clone + Send> Send for LinkedList<T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Clone + Sync> Sync for LinkedList<T, A> {}
+
 impl<T> LinkedList<T> {
     pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    /// Constructs a new, empty `LinkedList<T, A>` whose nodes will be allocated in `alloc`.
+    pub const fn new_in(alloc: A) -> Self {
         Self {
             head: None,
             tail: None,
             len: 0,
+            pool: NodePool::new(),
+            alloc,
             _pd: PhantomData,
         }
     }
+
+    /// Returns a reference to the underlying allocator.
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
     
     pub fn is_empty(&self) -> bool {
         self.head.is_none()
@@ -47,6 +82,59 @@ impl<T> LinkedList<T> {
     pub fn len(&self) -> usize {
         self.len
     }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _pd: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Provides a cursor at the front element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    pub fn cursor_front(&self) -> Cursor<'_, T, A> {
+        Cursor::new(0, self.head, self)
+    }
+
+    /// Provides a cursor with editing operations at the front element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut::new(0, self.head, self)
+    }
+
+    /// Provides a cursor at the back element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    pub fn cursor_back(&self) -> Cursor<'_, T, A> {
+        Cursor::new(self.len.saturating_sub(1), self.tail, self)
+    }
+
+    /// Provides a cursor with editing operations at the back element.
+    ///
+    /// The cursor is pointing to the "ghost" non-element if the list is empty.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, A> {
+        CursorMut::new(self.len.saturating_sub(1), self.tail, self)
+    }
+
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.iter().any(|e| e == x)
+    }
 }
 
 impl<T> Default for LinkedList<T> {
@@ -55,7 +143,7 @@ impl<T> Default for LinkedList<T> {
     }
 }
 
-impl<T> LinkedList<T> {
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
     pub fn front(&self) -> Option<&T> {
         unsafe { 
             self.head.as_ref().map(|node| &node.as_ref().element) 
@@ -81,8 +169,7 @@ impl<T> LinkedList<T> {
     }
     
     pub fn push_front(&mut self, element: T) {
-        let node = Box::new(Node::new(element));
-        let node_ptr = NonNull::from(Box::leak(node));
+        let node_ptr = self.alloc_node(element);
         // SAFETY: node_ptr is a unique pointer to a node we boxed and leaked
         unsafe {
             self.push_front_node(node_ptr);
@@ -90,12 +177,13 @@ impl<T> LinkedList<T> {
     }
     
     pub fn pop_front(&mut self) -> Option<T> {
-        self.pop_front_node().map(Node::into_element)
+        // SAFETY: the node was just unlinked from this list.
+        self.pop_front_node()
+            .map(|node| unsafe { self.free_node(node) })
     }
 
     pub fn push_back(&mut self, element: T) {
-        let node = Box::new(Node::new(element));
-        let node_ptr = NonNull::from(Box::leak(node));
+        let node_ptr = self.alloc_node(element);
         // SAFETY: node_ptr is a unique pointer to a node we boxed and leaked
         unsafe {
             self.push_back_node(node_ptr);
@@ -103,101 +191,988 @@ impl<T> LinkedList<T> {
     }
 
     pub fn pop_back(&mut self) -> Option<T> {
-        self.pop_back_node().map(Node::into_element)
+        // SAFETY: the node was just unlinked from this list.
+        self.pop_back_node()
+            .map(|node| unsafe { self.free_node(node) })
+    }
+
+    /// Adds an element first in the list, returning an error
+    /// instead of aborting if the node allocation fails.
+    pub fn try_push_front(&mut self, element: T) -> Result<(), TryReserveError> {
+        let node_ptr = self.try_alloc_node(element)?;
+        // SAFETY: node_ptr is a unique pointer to a node we boxed and leaked
+        unsafe {
+            self.push_front_node(node_ptr);
+        }
+        Ok(())
+    }
+
+    /// Appends an element to the back of the list, returning an error
+    /// instead of aborting if the node allocation fails.
+    pub fn try_push_back(&mut self, element: T) -> Result<(), TryReserveError> {
+        let node_ptr = self.try_alloc_node(element)?;
+        // SAFETY: node_ptr is a unique pointer to a node we boxed and leaked
+        unsafe {
+            self.push_back_node(node_ptr);
+        }
+        Ok(())
+    }
+}
+
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    /// Moves all elements from `other` to the end of the list.
+    ///
+    /// This reuses all the nodes from `other` and moves them into `self`. After
+    /// this operation, `other` becomes empty.
+    ///
+    /// This operation should compute in *O*(1) time and *O*(1) memory.
+    pub fn append(&mut self, other: &mut Self) {
+        match self.tail {
+            None => self.swap_nodes(other),
+            Some(tail) => {
+                if let Some(other_head) = other.head.take() {
+                    // Not creating new mutable (unique!) references overlapping `element`.
+                    unsafe {
+                        (*tail.as_ptr()).next = Some(other_head);
+                        (*other_head.as_ptr()).prev = Some(tail);
+                    }
+
+                    self.tail = other.tail.take();
+                    self.len += mem::replace(&mut other.len, 0);
+                }
+            }
+        }
+    }
+
+    /// Moves all elements from `other` to the front of the list.
+    ///
+    /// This reuses all the nodes from `other` and moves them into `self`. After
+    /// this operation, `other` becomes empty.
+    ///
+    /// This operation should compute in *O*(1) time and *O*(1) memory.
+    pub fn prepend(&mut self, other: &mut Self) {
+        other.append(self);
+        self.swap_nodes(other);
+    }
+
+    /// Splits the list into two at the given index. Returns everything after the given index,
+    /// including the index.
+    ///
+    /// This operation should compute in *O*(min(at, n - at)) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len;
+        assert!(at <= len, "Cannot split off at a nonexistent index");
+        if at == 0 {
+            return self.take_nodes();
+        } else if at == len {
+            return self.new_like();
+        }
+
+        // Below, we iterate towards the `i-1`th node, either from the start or the end,
+        // depending on which would be faster.
+        let split_node = if at - 1 <= len - 1 - (at - 1) {
+            let mut iter = self.iter_mut();
+            // instead of skipping using .skip() (which creates a new struct),
+            // we skip manually so we can access the head field without
+            // depending on implementation details of Skip
+            for _ in 0..at - 1 {
+                iter.next();
+            }
+            iter.head
+        } else {
+            // better off starting from the end
+            let mut iter = self.iter_mut();
+            for _ in 0..len - 1 - (at - 1) {
+                iter.next_back();
+            }
+            iter.tail
+        };
+
+        // SAFETY: split_node is the node at index at - 1 of this list.
+        unsafe { self.split_off_after_node(split_node, at) }
+    }
+
+    /// Removes all elements from the list.
+    ///
+    /// This operation should compute in *O*(*n*) time.
+    pub fn clear(&mut self) {
+        while let Some(node) = self.pop_front_node() {
+            // SAFETY: the node was just unlinked from this list.
+            drop(unsafe { self.free_node(node) });
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(&e)` returns false.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|elem| f(elem));
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all elements `e` for which `f(&mut e)` returns false.
+    /// This method operates in place, visiting each element exactly once in the
+    /// original order, and preserves the order of the retained elements.
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.extract_if(|elem| !f(elem)).for_each(drop);
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element should be removed.
+    ///
+    /// If the closure returns true, then the element is removed and yielded.
+    /// If the closure returns false, the element will remain in the list and will not be yielded
+    /// by the iterator.
+    ///
+    /// If the returned `ExtractIf` is not exhausted, e.g. because it is dropped without iterating
+    /// or the iteration short-circuits, then the remaining elements will be retained.
+    ///
+    /// If the closure panics, the list stays valid: every element it visited
+    /// before is either unlinked and returned or still in place.
+    pub fn extract_if<F>(&mut self, filter: F) -> ExtractIf<'_, T, F, A>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        // avoid borrow issues.
+        let it = self.head;
+        let old_len = self.len;
+
+        ExtractIf {
+            list: self,
+            it,
+            pred: filter,
+            idx: 0,
+            old_len,
+        }
+    }
+}
+
+// Relinking
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    /// Reverses the order of the elements in place.
+    ///
+    /// The elements are not moved, so pointers to them stay valid.
+    /// This operation should compute in *O*(*n*) time.
+    pub fn reverse(&mut self) {
+        let mut link = self.head;
+        while let Some(node) = link {
+            // Not creating new mutable (unique!) references overlapping `element`.
+            unsafe {
+                let node = node.as_ptr();
+                link = (*node).next;
+                mem::swap(&mut (*node).next, &mut (*node).prev);
+            }
+        }
+        mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Rotates the list `n` places to the left, so that the element at
+    /// index `n` becomes the first one.
+    ///
+    /// The elements are not moved, so pointers to them stay valid.
+    /// This operation should compute in *O*(min(n, len - n)) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > len`.
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len, "Cannot rotate by more than the length");
+        if n == 0 || n == self.len {
+            return;
+        }
+
+        // SAFETY: 0 < n < len, so the list has at least two nodes and
+        //  the node at n - 1 has a successor.
+        unsafe {
+            let (head, tail) = (self.head.unwrap(), self.tail.unwrap());
+            let new_tail = self.node_at(n - 1);
+            let new_head = (*new_tail.as_ptr()).next.unwrap();
+
+            // Close the ring...
+            (*tail.as_ptr()).next = Some(head);
+            (*head.as_ptr()).prev = Some(tail);
+            // ...and cut it open again before the new head.
+            (*new_tail.as_ptr()).next = None;
+            (*new_head.as_ptr()).prev = None;
+
+            self.head = Some(new_head);
+            self.tail = Some(new_tail);
+        }
+    }
+
+    /// Rotates the list `n` places to the right, so that the element at
+    /// index `len - n` becomes the first one.
+    ///
+    /// The elements are not moved, so pointers to them stay valid.
+    /// This operation should compute in *O*(min(n, len - n)) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n > len`.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.len, "Cannot rotate by more than the length");
+        self.rotate_left(self.len - n);
+    }
+
+    /// Swaps the first and the last element by relinking their nodes.
+    ///
+    /// The elements are not moved, so pointers to them stay valid.
+    /// This operation should compute in *O*(1) time.
+    pub fn swap_ends(&mut self) {
+        if self.len < 2 {
+            return;
+        }
+        if self.len == 2 {
+            self.reverse();
+            return;
+        }
+
+        // SAFETY: the list has at least three nodes, so head and tail
+        //  are distinct and both have an inner neighbour.
+        unsafe {
+            let (head, tail) = (self.head.unwrap(), self.tail.unwrap());
+            let after_head = (*head.as_ptr()).next.unwrap();
+            let before_tail = (*tail.as_ptr()).prev.unwrap();
+
+            (*tail.as_ptr()).prev = None;
+            (*tail.as_ptr()).next = Some(after_head);
+            (*after_head.as_ptr()).prev = Some(tail);
+
+            (*head.as_ptr()).next = None;
+            (*head.as_ptr()).prev = Some(before_tail);
+            (*before_tail.as_ptr()).next = Some(head);
+
+            self.head = Some(tail);
+            self.tail = Some(head);
+        }
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for LinkedList<T, A> {
+    fn clone(&self) -> Self {
+        let mut list = self.new_like();
+        list.extend(self.iter().cloned());
+        list
+    }
+
+    /// Overwrites the contents of `self` with a clone of the contents of `source`,
+    /// reusing the existing nodes where possible.
+    fn clone_from(&mut self, source: &Self) {
+        if self.len > source.len {
+            self.split_off(source.len);
+        }
+
+        let mut source_iter = source.iter();
+        for (element, source_element) in self.iter_mut().zip(&mut source_iter) {
+            element.clone_from(source_element);
+        }
+        self.extend(source_iter.cloned());
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator + Clone> fmt::Debug for LinkedList<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, A: Allocator + Clone> PartialEq for LinkedList<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Allocator + Clone> Eq for LinkedList<T, A> {}
+
+impl<T: PartialOrd, A: Allocator + Clone> PartialOrd for LinkedList<T, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, A: Allocator + Clone> Ord for LinkedList<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash, A: Allocator + Clone> Hash for LinkedList<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for element in self.iter() {
+            element.hash(state);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T, A: Allocator + Clone> Extend<T> for LinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter()
+            .for_each(move |element| self.push_back(element));
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator + Clone> Extend<&'a T> for LinkedList<T, A> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<'a, T, A: Allocator + Clone> IntoIterator for &'a LinkedList<T, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator + Clone> IntoIterator for &'a mut LinkedList<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, A: Allocator + Clone> IntoIterator for LinkedList<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+/// An iterator over the elements of a `LinkedList`.
+///
+/// This `struct` is created by [`LinkedList::iter()`].
+pub struct Iter<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _pd: PhantomData<&'a Node<T>>,
+}
+
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.head.map(|node| unsafe {
+                // Need an unbound lifetime to get 'a
+                let node = &*node.as_ptr();
+                self.len -= 1;
+                self.head = node.next;
+                &node.element
+            })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.tail.map(|node| unsafe {
+                // Need an unbound lifetime to get 'a
+                let node = &*node.as_ptr();
+                self.len -= 1;
+                self.tail = node.prev;
+                &node.element
+            })
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// A mutable iterator over the elements of a `LinkedList`.
+///
+/// This `struct` is created by [`LinkedList::iter_mut()`].
+pub struct IterMut<'a, T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _pd: PhantomData<&'a mut Node<T>>,
+}
+
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.head.map(|node| unsafe {
+                // Need an unbound lifetime to get 'a
+                let node = &mut *node.as_ptr();
+                self.len -= 1;
+                self.head = node.next;
+                &mut node.element
+            })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.tail.map(|node| unsafe {
+                // Need an unbound lifetime to get 'a
+                let node = &mut *node.as_ptr();
+                self.len -= 1;
+                self.tail = node.prev;
+                &mut node.element
+            })
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// An owning iterator over the elements of a `LinkedList`.
+///
+/// This `struct` is created by the `into_iter` method on [`LinkedList`].
+pub struct IntoIter<T, A: Allocator + Clone = Global> {
+    list: LinkedList<T, A>,
+}
+
+impl<T, A: Allocator + Clone> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T, A: Allocator + Clone> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, A: Allocator + Clone> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator + Clone> FusedIterator for IntoIter<T, A> {}
+
+/// An iterator produced by calling `extract_if` on `LinkedList`.
+pub struct ExtractIf<'a, T, F, A: Allocator + Clone = Global>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    list: &'a mut LinkedList<T, A>,
+    it: Link<T>,
+    pred: F,
+    idx: usize,
+    old_len: usize,
+}
+
+impl<T, F, A: Allocator + Clone> Iterator for ExtractIf<'_, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(node) = self.it {
+            unsafe {
+                // Advance first, so a panicking predicate leaves
+                // the list linked and the iterator consistent.
+                self.it = (*node.as_ptr()).next;
+                self.idx += 1;
+
+                if (self.pred)(&mut (*node.as_ptr()).element) {
+                    // `unlink_node` is okay with aliasing `element` references.
+                    self.list.unlink_node(node);
+                    return Some(self.list.free_node(node));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.idx))
+    }
+}
+
+impl<T: fmt::Debug, F, A: Allocator + Clone> fmt::Debug for ExtractIf<'_, T, F, A>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExtractIf").field(&self.list).finish()
     }
 }
 
 // Unsafe internals (from std 🙂)
-impl<T> LinkedList<T> {
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    /// Allocates a node for `element`, reusing a pooled one if there is any.
+    fn alloc_node(&mut self, element: T) -> NonNull<Node<T>> {
+        match self.pool.pop() {
+            Some(node) => unsafe {
+                node.as_ptr().write(Node::new(element));
+                node
+            },
+            None => NonNull::from(Box::leak(Box::new_in(Node::new(element), &self.alloc))),
+        }
+    }
+
+    /// Like `alloc_node`, but returns an error instead of aborting if the allocation fails.
+    fn try_alloc_node(&mut self, element: T) -> Result<NonNull<Node<T>>, TryReserveError> {
+        match self.pool.pop() {
+            Some(node) => unsafe {
+                node.as_ptr().write(Node::new(element));
+                Ok(node)
+            },
+            None => match Box::try_new_in(Node::new(element), &self.alloc) {
+                Ok(node) => Ok(NonNull::from(Box::leak(node))),
+                Err(_) => Err(AllocError {
+                    layout: Layout::new::<Node<T>>(),
+                }
+                .into()),
+            },
+        }
+    }
+
+    /// Moves the element out of an unlinked node and gives the node back to the pool.
+    ///
+    /// # Safety
+    /// `node` must point to a valid node that was boxed and leaked using the list's allocator
+    /// and is not linked into any list. The pointer should not be used again.
+    unsafe fn free_node(&mut self, node: NonNull<Node<T>>) -> T {
+        // The element is read exactly once, after that the node
+        //  is only treated as uninitialized memory.
+        unsafe {
+            let element = ptr::read(&(*node.as_ptr()).element);
+            self.pool.recycle(node, &self.alloc);
+            element
+        }
+    }
+
+    /// Swaps the nodes of two lists, keeping their pools in place.
+    fn swap_nodes(&mut self, other: &mut Self) {
+        mem::swap(&mut self.head, &mut other.head);
+        mem::swap(&mut self.tail, &mut other.tail);
+        mem::swap(&mut self.len, &mut other.len);
+    }
+
+    /// Creates an empty list with the same allocator, that pools its nodes
+    /// if this one does.
+    fn new_like(&self) -> Self {
+        let mut list = Self::new_in(self.alloc.clone());
+        list.pool = NodePool::with_capacity(self.pool.capacity());
+        list
+    }
+
+    /// Moves all the nodes out into a new list, keeping the pool in place.
+    fn take_nodes(&mut self) -> Self {
+        let mut list = self.new_like();
+        list.swap_nodes(self);
+        list
+    }
+
+    /// Returns the node at `index`, walking from whichever end is closer.
+    ///
+    /// # Safety
+    /// `index` must be less than `len`.
+    unsafe fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        debug_assert!(index < self.len);
+        unsafe {
+            if index <= self.len - 1 - index {
+                let mut node = self.head.unwrap_unchecked();
+                for _ in 0..index {
+                    node = (*node.as_ptr()).next.unwrap_unchecked();
+                }
+                node
+            } else {
+                let mut node = self.tail.unwrap_unchecked();
+                for _ in 0..self.len - 1 - index {
+                    node = (*node.as_ptr()).prev.unwrap_unchecked();
+                }
+                node
+            }
+        }
+    }
+
+    /// Detaches all nodes from a linked list as a series of nodes.
+    #[allow(clippy::type_complexity)]
+    fn detach_all_nodes(mut self) -> Option<(NonNull<Node<T>>, NonNull<Node<T>>, usize)> {
+        let head = self.head.take();
+        let tail = self.tail.take();
+        let len = mem::replace(&mut self.len, 0);
+        head.map(|head| (head, tail.unwrap(), len))
+    }
+
+    /// Splits the list before `split_node`, returning the first part.
+    ///
+    /// # Safety
+    /// `split_node` must be a node of this list at index `at`,
+    /// or `None` to split off the whole list.
+    unsafe fn split_off_before_node(&mut self, split_node: Link<T>, at: usize) -> Self {
+        // The split node is the new head node of the second part
+        if let Some(split_node) = split_node {
+            let first_part_head;
+            let first_part_tail;
+            unsafe {
+                first_part_tail = (*split_node.as_ptr()).prev.take();
+            }
+            if let Some(tail) = first_part_tail {
+                unsafe {
+                    (*tail.as_ptr()).next = None;
+                }
+                first_part_head = self.head;
+            } else {
+                first_part_head = None;
+            }
+
+            let first_part = LinkedList {
+                head: first_part_head,
+                tail: first_part_tail,
+                len: at,
+                pool: NodePool::with_capacity(self.pool.capacity()),
+                alloc: self.alloc.clone(),
+                _pd: PhantomData,
+            };
+
+            // Fix the head ptr of the second part
+            self.head = Some(split_node);
+            self.len -= at;
+
+            first_part
+        } else {
+            self.take_nodes()
+        }
+    }
+
+    /// Splits the list after `split_node`, returning the second part.
+    ///
+    /// # Safety
+    /// `split_node` must be a node of this list at index `at - 1`,
+    /// or `None` to split off the whole list.
+    unsafe fn split_off_after_node(&mut self, split_node: Link<T>, at: usize) -> Self {
+        // The split node is the new tail node of the first part and owns
+        // the head of the second part.
+        if let Some(split_node) = split_node {
+            let second_part_head;
+            let second_part_tail;
+            unsafe {
+                second_part_head = (*split_node.as_ptr()).next.take();
+            }
+            if let Some(head) = second_part_head {
+                unsafe {
+                    (*head.as_ptr()).prev = None;
+                }
+                second_part_tail = self.tail;
+            } else {
+                second_part_tail = None;
+            }
+
+            let second_part = LinkedList {
+                head: second_part_head,
+                tail: second_part_tail,
+                len: self.len - at,
+                pool: NodePool::with_capacity(self.pool.capacity()),
+                alloc: self.alloc.clone(),
+                _pd: PhantomData,
+            };
+
+            // Fix the tail ptr of the first part
+            self.tail = Some(split_node);
+            self.len = at;
+
+            second_part
+        } else {
+            self.take_nodes()
+        }
+    }
+}
+
+/// The head, tail and length of a list, borrowed together.
+type Ends<'a, N> = (
+    &'a mut Option<NonNull<N>>,
+    &'a mut Option<NonNull<N>>,
+    &'a mut usize,
+);
+
+/// The `next` and `prev` pointers of a list node.
+trait NodeLinks: Sized {
+    /// # Safety
+    /// `node` must point to a live node.
+    unsafe fn next(node: NonNull<Self>) -> Option<NonNull<Self>>;
+
+    /// # Safety
+    /// `node` must point to a live node.
+    unsafe fn prev(node: NonNull<Self>) -> Option<NonNull<Self>>;
+
+    /// # Safety
+    /// `node` must point to a live node.
+    unsafe fn set_next(node: NonNull<Self>, next: Option<NonNull<Self>>);
+
+    /// # Safety
+    /// `node` must point to a live node.
+    unsafe fn set_prev(node: NonNull<Self>, prev: Option<NonNull<Self>>);
+}
+
+impl<T> NodeLinks for Node<T> {
+    // Only the link fields are accessed, never the whole node, so that
+    // pointers into `element` stay valid.
+
+    unsafe fn next(node: NonNull<Self>) -> Link<T> {
+        unsafe { (*node.as_ptr()).next }
+    }
+
+    unsafe fn prev(node: NonNull<Self>) -> Link<T> {
+        unsafe { (*node.as_ptr()).prev }
+    }
+
+    unsafe fn set_next(node: NonNull<Self>, next: Link<T>) {
+        unsafe { (*node.as_ptr()).next = next };
+    }
+
+    unsafe fn set_prev(node: NonNull<Self>, prev: Link<T>) {
+        unsafe { (*node.as_ptr()).prev = prev };
+    }
+}
+
+/// The unlink and relink logic of a doubly linked list, written against
+/// [`NodeLinks`] so that [`LinkedList`] and [`intrusive::List`] share it.
+///
+/// # Safety
+/// `ends` must return the head, tail and length of a well-formed list whose
+/// nodes stay live for as long as they are linked.
+unsafe trait NodeList {
+    type Node: NodeLinks;
+
+    /// Returns the head, tail and length of the list.
+    fn ends(&mut self) -> Ends<'_, Self::Node>;
+
     /// Adds the given node to the front of the list.
     ///
     /// # Safety
-    /// `node` must point to a valid node that was boxed and leaked using the list's allocator.
-    /// This method takes ownership of the node, so the pointer should not be used again.
-    unsafe fn push_front_node(&mut self, node: NonNull<Node<T>>) {
+    /// `node` must point to a valid node that is not on any list. This method
+    /// takes ownership of the node, so the pointer should not be used again.
+    /// The nodes of a `LinkedList` must be boxed and leaked using its allocator.
+    unsafe fn push_front_node(&mut self, node: NonNull<Self::Node>) {
         // This method takes care not to create mutable references to whole nodes,
         // to maintain validity of aliasing pointers into `element`.
+        let (head, tail, len) = self.ends();
         unsafe {
-            (*node.as_ptr()).next = self.head;
-            (*node.as_ptr()).prev = None;
+            Self::Node::set_next(node, *head);
+            Self::Node::set_prev(node, None);
             let node = Some(node);
 
-            match self.head {
-                None => self.tail = node,
+            match *head {
+                None => *tail = node,
                 // Not creating new mutable (unique!) references overlapping `element`.
-                Some(head) => (*head.as_ptr()).prev = node,
+                Some(head) => Self::Node::set_prev(head, node),
             }
 
-            self.head = node;
-            self.len += 1;
+            *head = node;
+            *len += 1;
         }
     }
 
     /// Removes and returns the node at the front of the list.
-    fn pop_front_node(&mut self) -> Option<Box<Node<T>>> {
+    /// The caller takes ownership of the unlinked node.
+    fn pop_front_node(&mut self) -> Option<NonNull<Self::Node>> {
         // This method takes care not to create mutable references to whole nodes,
         // to maintain validity of aliasing pointers into `element`.
-        self.head.map(|node| unsafe {
-            let node = Box::from_raw(node.as_ptr());
-            self.head = node.next;
+        let (head, tail, len) = self.ends();
+        let node = (*head)?;
+        unsafe {
+            *head = Self::Node::next(node);
 
-            match self.head {
-                None => self.tail = None,
+            match *head {
+                None => *tail = None,
                 // Not creating new mutable (unique!) references overlapping `element`.
-                Some(head) => (*head.as_ptr()).prev = None,
+                Some(head) => Self::Node::set_prev(head, None),
             }
+        }
 
-            self.len -= 1;
-            node
-        })
+        *len -= 1;
+        Some(node)
     }
 
     /// Adds the given node to the back of the list.
     ///
     /// # Safety
-    /// `node` must point to a valid node that was boxed and leaked using the list's allocator.
-    /// This method takes ownership of the node, so the pointer should not be used again.
-    unsafe fn push_back_node(&mut self, node: NonNull<Node<T>>) {
+    /// `node` must point to a valid node that is not on any list. This method
+    /// takes ownership of the node, so the pointer should not be used again.
+    /// The nodes of a `LinkedList` must be boxed and leaked using its allocator.
+    unsafe fn push_back_node(&mut self, node: NonNull<Self::Node>) {
         // This method takes care not to create mutable references to whole nodes,
         // to maintain validity of aliasing pointers into `element`.
+        let (head, tail, len) = self.ends();
         unsafe {
-            (*node.as_ptr()).next = None;
-            (*node.as_ptr()).prev = self.tail;
+            Self::Node::set_next(node, None);
+            Self::Node::set_prev(node, *tail);
             let node = Some(node);
 
-            match self.tail {
-                None => self.head = node,
+            match *tail {
+                None => *head = node,
                 // Not creating new mutable (unique!) references overlapping `element`.
-                Some(tail) => (*tail.as_ptr()).next = node,
+                Some(tail) => Self::Node::set_next(tail, node),
             }
 
-            self.tail = node;
-            self.len += 1;
+            *tail = node;
+            *len += 1;
         }
     }
 
     /// Removes and returns the node at the back of the list.
-    fn pop_back_node(&mut self) -> Option<Box<Node<T>>> {
+    /// The caller takes ownership of the unlinked node.
+    fn pop_back_node(&mut self) -> Option<NonNull<Self::Node>> {
+        // This method takes care not to create mutable references to whole nodes,
+        // to maintain validity of aliasing pointers into `element`.
+        let (head, tail, len) = self.ends();
+        let node = (*tail)?;
+        unsafe {
+            *tail = Self::Node::prev(node);
+
+            match *tail {
+                None => *head = None,
+                // Not creating new mutable (unique!) references overlapping `element`.
+                Some(tail) => Self::Node::set_next(tail, None),
+            }
+        }
+
+        *len -= 1;
+        Some(node)
+    }
+
+    /// Unlinks the specified node from the current list.
+    ///
+    /// # Safety
+    /// `node` must point to a valid node of this list. The caller takes
+    /// ownership of the unlinked node.
+    unsafe fn unlink_node(&mut self, node: NonNull<Self::Node>) {
         // This method takes care not to create mutable references to whole nodes,
         // to maintain validity of aliasing pointers into `element`.
-        self.tail.map(|node| unsafe {
-            let node = Box::from_raw(node.as_ptr());
-            self.tail = node.prev;
+        let (head, tail, len) = self.ends();
+        unsafe {
+            let prev = Self::Node::prev(node);
+            let next = Self::Node::next(node);
+            match prev {
+                None => *head = next,
+                // Not creating new mutable (unique!) references overlapping `element`.
+                Some(prev) => Self::Node::set_next(prev, next),
+            }
 
-            match self.tail {
-                None => self.head = None,
+            match next {
+                None => *tail = prev,
                 // Not creating new mutable (unique!) references overlapping `element`.
-                Some(tail) => (*tail.as_ptr()).next = None,
+                Some(next) => Self::Node::set_prev(next, prev),
             }
+        }
+
+        *len -= 1;
+    }
+
+    /// Splices a series of nodes between two existing nodes.
+    ///
+    /// # Safety
+    /// `existing_prev` and `existing_next` must be adjacent nodes of this list
+    /// (or `None` for the list ends), and `splice_start..=splice_end` must be
+    /// a chain of `splice_length` nodes not owned by any list.
+    unsafe fn splice_nodes(
+        &mut self,
+        existing_prev: Option<NonNull<Self::Node>>,
+        existing_next: Option<NonNull<Self::Node>>,
+        splice_start: NonNull<Self::Node>,
+        splice_end: NonNull<Self::Node>,
+        splice_length: usize,
+    ) {
+        // This method takes care not to create mutable references to whole nodes,
+        // to maintain validity of aliasing pointers into `element`.
+        let (head, tail, len) = self.ends();
+        unsafe {
+            match existing_prev {
+                None => *head = Some(splice_start),
+                Some(prev) => Self::Node::set_next(prev, Some(splice_start)),
+            }
+            match existing_next {
+                None => *tail = Some(splice_end),
+                Some(next) => Self::Node::set_prev(next, Some(splice_end)),
+            }
+            Self::Node::set_prev(splice_start, existing_prev);
+            Self::Node::set_next(splice_end, existing_next);
+        }
+
+        *len += splice_length;
+    }
+}
+
+// SAFETY: the list owns its nodes until they are unlinked.
+unsafe impl<T, A: Allocator + Clone> NodeList for LinkedList<T, A> {
+    type Node = Node<T>;
 
-            self.len -= 1;
-            node
-        })
+    fn ends(&mut self) -> Ends<'_, Node<T>> {
+        (&mut self.head, &mut self.tail, &mut self.len)
     }
 }
 
-unsafe impl<#[may_dangle] T> Drop for LinkedList<T> {
+unsafe impl<#[may_dangle] T, A: Allocator + Clone> Drop for LinkedList<T, A> {
     fn drop(&mut self) {
-        while self.pop_front_node().is_some() {}
+        self.clear();
+        self.pool.shrink(&self.alloc);
     }
 }
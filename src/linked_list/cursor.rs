@@ -0,0 +1,403 @@
+use super::{Link, LinkedList, NodeList};
+use std::alloc::{Allocator, Global};
+
+/// A cursor over a `LinkedList`.
+///
+/// A `Cursor` is like an iterator, except that it can freely seek back-and-forth.
+///
+/// Cursors always rest between two elements in the list, and index in a logically circular way.
+/// To accommodate this, there is a "ghost" non-element that yields `None` between the head and
+/// tail of the list.
+///
+/// When created, cursors start at the front of the list, or the "ghost" non-element if the list is empty.
+pub struct Cursor<'a, T, A: Allocator + Clone = Global> {
+    index: usize,
+    current: Link<T>,
+    list: &'a LinkedList<T, A>,
+}
+
+unsafe impl<T: Sync, A: Allocator + Clone + Sync> Send for Cursor<'_, T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Clone + Sync> Sync for Cursor<'_, T, A> {}
+
+impl<T, A: Allocator + Clone> Clone for Cursor<'_, T, A> {
+    fn clone(&self) -> Self {
+        Cursor { ..*self }
+    }
+}
+
+impl<'a, T, A: Allocator + Clone> Cursor<'a, T, A> {
+    pub(super) fn new(index: usize, current: Link<T>, list: &'a LinkedList<T, A>) -> Self {
+        Self {
+            index,
+            current,
+            list,
+        }
+    }
+
+    /// Returns the cursor position index within the `LinkedList`.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    pub fn index(&self) -> Option<usize> {
+        let _ = self.current?;
+        Some(self.index)
+    }
+
+    /// Moves the cursor to the next element of the `LinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the first element of the `LinkedList`. If it is pointing to the last
+    /// element of the `LinkedList` then this will move it to the "ghost" non-element.
+    pub fn move_next(&mut self) {
+        match self.current.take() {
+            // We had no current element; the cursor was sitting at the start position
+            // Next element should be the head of the list
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+            // We had a previous element, so let's go to its next
+            Some(current) => unsafe {
+                self.current = (*current.as_ptr()).next;
+                self.index += 1;
+            },
+        }
+    }
+
+    /// Moves the cursor to the previous element of the `LinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the last element of the `LinkedList`. If it is pointing to the first
+    /// element of the `LinkedList` then this will move it to the "ghost" non-element.
+    pub fn move_prev(&mut self) {
+        match self.current.take() {
+            // No current. We're at the start of the list. Yield None and jump to the end.
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+            // Have a prev. Yield it and go to the previous element.
+            Some(current) => unsafe {
+                self.current = (*current.as_ptr()).prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            },
+        }
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    pub fn current(&self) -> Option<&'a T> {
+        unsafe { self.current.map(|current| &(*current.as_ptr()).element) }
+    }
+
+    /// Returns a reference to the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the `LinkedList`. If it is pointing to the last
+    /// element of the `LinkedList` then this returns `None`.
+    pub fn peek_next(&self) -> Option<&'a T> {
+        unsafe {
+            let next = match self.current {
+                None => self.list.head,
+                Some(current) => (*current.as_ptr()).next,
+            };
+            next.map(|next| &(*next.as_ptr()).element)
+        }
+    }
+
+    /// Returns a reference to the previous element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the last element of the `LinkedList`. If it is pointing to the first
+    /// element of the `LinkedList` then this returns `None`.
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        unsafe {
+            let prev = match self.current {
+                None => self.list.tail,
+                Some(current) => (*current.as_ptr()).prev,
+            };
+            prev.map(|prev| &(*prev.as_ptr()).element)
+        }
+    }
+}
+
+/// A cursor over a `LinkedList` with editing operations.
+///
+/// A `CursorMut` is like an iterator, except that it can freely seek back-and-forth, and can
+/// safely mutate the list during iteration. This is because the lifetime of its yielded
+/// references is tied to its own lifetime, instead of just the underlying list. This means
+/// cursors cannot yield multiple elements at once.
+///
+/// Cursors always rest between two elements in the list, and index in a logically circular way.
+/// To accommodate this, there is a "ghost" non-element that yields `None` between the head and
+/// tail of the list.
+pub struct CursorMut<'a, T, A: Allocator + Clone = Global> {
+    index: usize,
+    current: Link<T>,
+    list: &'a mut LinkedList<T, A>,
+}
+
+unsafe impl<T: Send, A: Allocator + Clone + Send> Send for CursorMut<'_, T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Clone + Sync> Sync for CursorMut<'_, T, A> {}
+
+impl<'a, T, A: Allocator + Clone> CursorMut<'a, T, A> {
+    pub(super) fn new(index: usize, current: Link<T>, list: &'a mut LinkedList<T, A>) -> Self {
+        Self {
+            index,
+            current,
+            list,
+        }
+    }
+
+    /// Returns the cursor position index within the `LinkedList`.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    pub fn index(&self) -> Option<usize> {
+        let _ = self.current?;
+        Some(self.index)
+    }
+
+    /// Moves the cursor to the next element of the `LinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the first element of the `LinkedList`. If it is pointing to the last
+    /// element of the `LinkedList` then this will move it to the "ghost" non-element.
+    pub fn move_next(&mut self) {
+        match self.current.take() {
+            // We had no current element; the cursor was sitting at the start position
+            // Next element should be the head of the list
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+            // We had a previous element, so let's go to its next
+            Some(current) => unsafe {
+                self.current = (*current.as_ptr()).next;
+                self.index += 1;
+            },
+        }
+    }
+
+    /// Moves the cursor to the previous element of the `LinkedList`.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the last element of the `LinkedList`. If it is pointing to the first
+    /// element of the `LinkedList` then this will move it to the "ghost" non-element.
+    pub fn move_prev(&mut self) {
+        match self.current.take() {
+            // No current. We're at the start of the list. Yield None and jump to the end.
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.saturating_sub(1);
+            }
+            // Have a prev. Yield it and go to the previous element.
+            Some(current) => unsafe {
+                self.current = (*current.as_ptr()).prev;
+                self.index = self.index.checked_sub(1).unwrap_or(self.list.len);
+            },
+        }
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    pub fn current(&mut self) -> Option<&mut T> {
+        unsafe { self.current.map(|current| &mut (*current.as_ptr()).element) }
+    }
+
+    /// Returns a reference to the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the `LinkedList`. If it is pointing to the last
+    /// element of the `LinkedList` then this returns `None`.
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        unsafe {
+            let next = match self.current {
+                None => self.list.head,
+                Some(current) => (*current.as_ptr()).next,
+            };
+            next.map(|next| &mut (*next.as_ptr()).element)
+        }
+    }
+
+    /// Returns a reference to the previous element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the last element of the `LinkedList`. If it is pointing to the first
+    /// element of the `LinkedList` then this returns `None`.
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        unsafe {
+            let prev = match self.current {
+                None => self.list.tail,
+                Some(current) => (*current.as_ptr()).prev,
+            };
+            prev.map(|prev| &mut (*prev.as_ptr()).element)
+        }
+    }
+
+    /// Returns a read-only cursor pointing to the current element.
+    ///
+    /// The lifetime of the returned `Cursor` is bound to that of the
+    /// `CursorMut`, which means it cannot outlive the `CursorMut` and that the
+    /// `CursorMut` is frozen for the lifetime of the `Cursor`.
+    pub fn as_cursor(&self) -> Cursor<'_, T, A> {
+        Cursor::new(self.index, self.current, self.list)
+    }
+
+    /// Inserts a new element into the `LinkedList` after the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new element is
+    /// inserted at the front of the `LinkedList`.
+    pub fn insert_after(&mut self, item: T) {
+        let node = self.list.alloc_node(item);
+        // SAFETY: node is a unique pointer to a node we allocated,
+        //  and it goes between current and its next neighbour.
+        unsafe {
+            let node_next = match self.current {
+                None => self.list.head,
+                Some(current) => (*current.as_ptr()).next,
+            };
+            self.list
+                .splice_nodes(self.current, node_next, node, node, 1);
+        }
+        if self.current.is_none() {
+            // The "ghost" non-element's index has changed.
+            self.index = self.list.len;
+        }
+    }
+
+    /// Inserts a new element into the `LinkedList` before the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new element is
+    /// inserted at the end of the `LinkedList`.
+    pub fn insert_before(&mut self, item: T) {
+        let node = self.list.alloc_node(item);
+        // SAFETY: node is a unique pointer to a node we allocated,
+        //  and it goes between current and its previous neighbour.
+        unsafe {
+            let node_prev = match self.current {
+                None => self.list.tail,
+                Some(current) => (*current.as_ptr()).prev,
+            };
+            self.list
+                .splice_nodes(node_prev, self.current, node, node, 1);
+        }
+        self.index += 1;
+    }
+
+    /// Removes the current element from the `LinkedList`.
+    ///
+    /// The element that was removed is returned, and the cursor is
+    /// moved to point to the next element in the `LinkedList`.
+    ///
+    /// If the cursor is currently pointing to the "ghost" non-element then no element
+    /// is removed and `None` is returned.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let unlinked_node = self.current?;
+        // SAFETY: current is a node of this list, and after unlinking
+        //  we own it again.
+        unsafe {
+            self.current = (*unlinked_node.as_ptr()).next;
+            self.list.unlink_node(unlinked_node);
+            Some(self.list.free_node(unlinked_node))
+        }
+    }
+
+    /// Inserts the elements from the given `LinkedList` after the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new elements are
+    /// inserted at the start of the `LinkedList`.
+    pub fn splice_after(&mut self, list: LinkedList<T, A>) {
+        let (splice_head, splice_tail, splice_len) = match list.detach_all_nodes() {
+            Some(parts) => parts,
+            None => return,
+        };
+        // SAFETY: the detached nodes are no longer owned by any list,
+        //  and they go between current and its next neighbour.
+        unsafe {
+            let node_next = match self.current {
+                None => self.list.head,
+                Some(current) => (*current.as_ptr()).next,
+            };
+            self.list.splice_nodes(
+                self.current,
+                node_next,
+                splice_head,
+                splice_tail,
+                splice_len,
+            );
+        }
+        if self.current.is_none() {
+            // The "ghost" non-element's index has changed.
+            self.index = self.list.len;
+        }
+    }
+
+    /// Inserts the elements from the given `LinkedList` before the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new elements are
+    /// inserted at the end of the `LinkedList`.
+    pub fn splice_before(&mut self, list: LinkedList<T, A>) {
+        let (splice_head, splice_tail, splice_len) = match list.detach_all_nodes() {
+            Some(parts) => parts,
+            None => return,
+        };
+        // SAFETY: the detached nodes are no longer owned by any list,
+        //  and they go between current and its previous neighbour.
+        unsafe {
+            let node_prev = match self.current {
+                None => self.list.tail,
+                Some(current) => (*current.as_ptr()).prev,
+            };
+            self.list.splice_nodes(
+                node_prev,
+                self.current,
+                splice_head,
+                splice_tail,
+                splice_len,
+            );
+        }
+        self.index += splice_len;
+    }
+
+    /// Splits the list into two after the current element. This will return a
+    /// new list consisting of everything after the cursor, with the original
+    /// list retaining everything before.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the entire contents
+    /// of the `LinkedList` are moved.
+    pub fn split_after(&mut self) -> LinkedList<T, A> {
+        let split_off_idx = if self.index == self.list.len {
+            0
+        } else {
+            self.index + 1
+        };
+        if self.index == self.list.len {
+            // The "ghost" non-element's index has changed to 0.
+            self.index = 0;
+        }
+        // SAFETY: current is a node of this list at index split_off_idx - 1, or None.
+        unsafe { self.list.split_off_after_node(self.current, split_off_idx) }
+    }
+
+    /// Splits the list into two before the current element. This will return a
+    /// new list consisting of everything before the cursor, with the original
+    /// list retaining everything after.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the entire contents
+    /// of the `LinkedList` are moved.
+    pub fn split_before(&mut self) -> LinkedList<T, A> {
+        let split_off_idx = self.index;
+        self.index = 0;
+        // SAFETY: current is a node of this list at index split_off_idx, or None.
+        unsafe { self.list.split_off_before_node(self.current, split_off_idx) }
+    }
+}
@@ -0,0 +1,373 @@
+//! Intrusive doubly linked lists.
+//!
+//! The elements embed a [`Link`] for every list they can be on, and an
+//! [`Adapter`] tells a [`List`] which of those links to use. The list never
+//! allocates: it borrows pinned elements and links them together through
+//! their `Link` fields, with the same unlink and relink code as
+//! [`LinkedList`](super::LinkedList).
+//!
+//! ```
+//! use serial::intrusive::{Link, List};
+//! use std::pin::pin;
+//!
+//! struct Timer {
+//!     deadline: u64,
+//!     wheel: Link,
+//!     expired: Link,
+//! }
+//!
+//! serial::intrusive_adapter!(WheelAdapter = Timer { wheel });
+//! serial::intrusive_adapter!(ExpiredAdapter = Timer { expired });
+//!
+//! let timer = pin!(Timer { deadline: 10, wheel: Link::new(), expired: Link::new() });
+//! let timer = timer.into_ref();
+//!
+//! let mut wheel = List::<WheelAdapter>::new();
+//! let mut expired = List::<ExpiredAdapter>::new();
+//! wheel.push_back(timer);
+//! expired.push_back(timer);
+//! assert_eq!(wheel.front().unwrap().deadline, 10);
+//! assert_eq!(expired.pop_front().unwrap().deadline, 10);
+//! assert!(timer.wheel.is_linked());
+//! assert!(!timer.expired.is_linked());
+//! ```
+
+use super::{Ends, NodeLinks, NodeList};
+use std::cell::Cell;
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::{PhantomData, PhantomPinned};
+use std::mem;
+use std::pin::Pin;
+use std::ptr::{self, NonNull};
+
+/// A pointer to the link of the next or previous element.
+///
+/// It is always derived from a pointer to the whole element, so that
+/// [`Adapter::get_value`] can go back from the link to its element.
+type LinkPtr = Option<NonNull<Link>>;
+
+/// The field an element embeds to be linked into a [`List`].
+///
+/// An element needs one `Link` for every list it can be on at the same time.
+pub struct Link {
+    next: Cell<LinkPtr>,
+    prev: Cell<LinkPtr>,
+    linked: Cell<bool>,
+    _pin: PhantomPinned,
+}
+
+impl Link {
+    /// Creates a link that is not on any list.
+    pub const fn new() -> Self {
+        Self {
+            next: Cell::new(None),
+            prev: Cell::new(None),
+            linked: Cell::new(false),
+            _pin: PhantomPinned,
+        }
+    }
+
+    /// Returns `true` if the element is on a list through this link.
+    pub fn is_linked(&self) -> bool {
+        self.linked.get()
+    }
+}
+
+impl NodeLinks for Link {
+    // The pointers were derived from whole elements, and only shared
+    // references to the link fields are created from them.
+
+    unsafe fn next(node: NonNull<Self>) -> LinkPtr {
+        unsafe { node.as_ref().next.get() }
+    }
+
+    unsafe fn prev(node: NonNull<Self>) -> LinkPtr {
+        unsafe { node.as_ref().prev.get() }
+    }
+
+    unsafe fn set_next(node: NonNull<Self>, next: LinkPtr) {
+        unsafe { node.as_ref().next.set(next) };
+    }
+
+    unsafe fn set_prev(node: NonNull<Self>, prev: LinkPtr) {
+        unsafe { node.as_ref().prev.set(prev) };
+    }
+}
+
+impl Default for Link {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Link")
+            .field("linked", &self.is_linked())
+            .finish()
+    }
+}
+
+/// Maps an element type to one of its [`Link`] fields.
+///
+/// Implement it with [`intrusive_adapter!`](crate::intrusive_adapter).
+///
+/// # Safety
+/// `get_link` must always return a pointer to the same field of `value`,
+/// derived from `value` without going through a reference, and `get_value`
+/// must map a pointer to that field back to its element.
+pub unsafe trait Adapter {
+    type Value;
+
+    /// Returns a pointer to the link of `value` used by the list.
+    ///
+    /// # Safety
+    /// `value` must point to a live element.
+    unsafe fn get_link(value: *const Self::Value) -> NonNull<Link>;
+
+    /// Returns the element containing `link`.
+    ///
+    /// # Safety
+    /// `link` must have been returned by `get_link`.
+    unsafe fn get_value(link: NonNull<Link>) -> NonNull<Self::Value>;
+}
+
+/// A doubly linked list of elements that embed their own [`Link`].
+///
+/// The list borrows its elements for `'a`, and unlinks all of them when dropped.
+pub struct List<'a, A: Adapter> {
+    head: LinkPtr,
+    tail: LinkPtr,
+    len: usize,
+    _pd: PhantomData<(A, Pin<&'a A::Value>)>,
+}
+
+impl<'a, A: Adapter> List<'a, A> {
+    /// Creates an empty `List`.
+    pub const fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            _pd: PhantomData,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn iter(&self) -> Iter<'a, '_, A> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _pd: PhantomData,
+        }
+    }
+
+    pub fn front(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: the head is linked into this list.
+        self.head.map(|link| unsafe { Self::value(link) })
+    }
+
+    pub fn back(&self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: the tail is linked into this list.
+        self.tail.map(|link| unsafe { Self::value(link) })
+    }
+
+    /// Links `value` first in the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is already on a list through this link.
+    pub fn push_front(&mut self, value: Pin<&'a A::Value>) {
+        let link = Self::link(value);
+        // SAFETY: `value` is borrowed for as long as the list and was not linked.
+        unsafe { self.push_front_node(link) };
+    }
+
+    /// Links `value` last in the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is already on a list through this link.
+    pub fn push_back(&mut self, value: Pin<&'a A::Value>) {
+        let link = Self::link(value);
+        // SAFETY: `value` is borrowed for as long as the list and was not linked.
+        unsafe { self.push_back_node(link) };
+    }
+
+    /// Unlinks the first element and returns it.
+    pub fn pop_front(&mut self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: the head is linked into this list.
+        self.head.map(|link| unsafe { self.unlink(link) })
+    }
+
+    /// Unlinks the last element and returns it.
+    pub fn pop_back(&mut self) -> Option<Pin<&'a A::Value>> {
+        // SAFETY: the tail is linked into this list.
+        self.tail.map(|link| unsafe { self.unlink(link) })
+    }
+
+    /// Unlinks `value` from the list.
+    ///
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Safety
+    /// `value` must be on this list.
+    pub unsafe fn remove(&mut self, value: Pin<&'a A::Value>) {
+        // SAFETY: `value` is a live element.
+        let link = unsafe { A::get_link(ptr::from_ref(Pin::get_ref(value))) };
+        // SAFETY: the caller guarantees that the link is on this list.
+        unsafe { self.unlink(link) };
+    }
+
+    /// Moves all elements from `other` to the end of the list.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn append(&mut self, other: &mut Self) {
+        if let Some(other_head) = other.head.take() {
+            let other_tail = other.tail.take().unwrap();
+            let other_len = mem::replace(&mut other.len, 0);
+            // SAFETY: the nodes of `other` were just detached, and both lists
+            // borrow their elements for 'a.
+            unsafe { self.splice_nodes(self.tail, None, other_head, other_tail, other_len) };
+        }
+    }
+
+    /// Unlinks all elements from the list.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Marks the link of `value` as linked and returns it.
+    fn link(value: Pin<&'a A::Value>) -> NonNull<Link> {
+        // The pointer is taken from the whole element, not from its link field,
+        // so that `get_value` may later step back to the element.
+        // SAFETY: `value` is a live element.
+        let link = unsafe { A::get_link(ptr::from_ref(Pin::get_ref(value))) };
+        // SAFETY: the link lives as long as `value`.
+        let linked = unsafe { &link.as_ref().linked };
+        assert!(!linked.replace(true), "element is already linked");
+        link
+    }
+
+    /// Unlinks `link` from the list and returns its element.
+    ///
+    /// # Safety
+    /// `link` must be on this list.
+    unsafe fn unlink(&mut self, link: NonNull<Link>) -> Pin<&'a A::Value> {
+        unsafe {
+            debug_assert!(link.as_ref().is_linked());
+            self.unlink_node(link);
+            link.as_ref().linked.set(false);
+            Self::value(link)
+        }
+    }
+
+    /// # Safety
+    /// `link` must be the link of an element borrowed by this list.
+    unsafe fn value(link: NonNull<Link>) -> Pin<&'a A::Value> {
+        // The elements were pinned and are borrowed for 'a.
+        unsafe { Pin::new_unchecked(A::get_value(link).as_ref()) }
+    }
+}
+
+// SAFETY: the list borrows its linked elements for 'a.
+unsafe impl<A: Adapter> NodeList for List<'_, A> {
+    type Node = Link;
+
+    fn ends(&mut self) -> Ends<'_, Link> {
+        (&mut self.head, &mut self.tail, &mut self.len)
+    }
+}
+
+impl<A: Adapter> Default for List<'_, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Adapter> fmt::Debug for List<'_, A>
+where
+    A::Value: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<A: Adapter> Drop for List<'_, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<'a, 'l, A: Adapter> IntoIterator for &'l List<'a, A> {
+    type Item = Pin<&'a A::Value>;
+    type IntoIter = Iter<'a, 'l, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`List`].
+///
+/// This `struct` is created by [`List::iter()`].
+pub struct Iter<'a, 'l, A: Adapter> {
+    head: LinkPtr,
+    tail: LinkPtr,
+    len: usize,
+    _pd: PhantomData<&'l List<'a, A>>,
+}
+
+impl<A: Adapter> Clone for Iter<'_, '_, A> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+impl<'a, A: Adapter> Iterator for Iter<'a, '_, A> {
+    type Item = Pin<&'a A::Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.head.map(|link| unsafe {
+                self.len -= 1;
+                self.head = link.as_ref().next.get();
+                List::<A>::value(link)
+            })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<A: Adapter> DoubleEndedIterator for Iter<'_, '_, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            None
+        } else {
+            self.tail.map(|link| unsafe {
+                self.len -= 1;
+                self.tail = link.as_ref().prev.get();
+                List::<A>::value(link)
+            })
+        }
+    }
+}
+
+impl<A: Adapter> ExactSizeIterator for Iter<'_, '_, A> {}
+
+impl<A: Adapter> FusedIterator for Iter<'_, '_, A> {}
@@ -0,0 +1,136 @@
+use super::{Link, LinkedList, Node};
+use std::alloc::{Allocator, Global};
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+
+/// A free-list of recycled nodes kept around to be reused by later pushes.
+///
+/// Pooled nodes are chained through `next` and their `element` is uninitialized.
+/// The pool saves allocator round trips, not memory locality: every node is
+/// still a separate allocation, so nodes can move freely between lists with
+/// `append`, `split_off` or the cursors, and each list is free to deallocate
+/// whatever nodes it ends up owning.
+pub(super) struct NodePool<T> {
+    free: Link<T>,
+    len: usize,
+    /// The most nodes the pool keeps, 0 if pooling is disabled.
+    capacity: usize,
+}
+
+impl<T> NodePool<T> {
+    pub(super) const fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub(super) const fn with_capacity(capacity: usize) -> Self {
+        Self {
+            free: None,
+            len: 0,
+            capacity,
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(super) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Allocates nodes into the pool until it is full.
+    pub(super) fn fill<A: Allocator>(&mut self, alloc: &A) {
+        while self.len < self.capacity {
+            let node = NonNull::from(Box::leak(Box::<Node<T>, _>::new_uninit_in(alloc)));
+            // SAFETY: the node is a fresh allocation with the layout of `Node<T>`.
+            unsafe { self.push(node.cast()) };
+        }
+    }
+
+    /// Takes an uninitialized node out of the pool, if there is any.
+    pub(super) fn pop(&mut self) -> Link<T> {
+        let node = self.free?;
+        unsafe { self.free = (*node.as_ptr()).next };
+        self.len -= 1;
+        Some(node)
+    }
+
+    /// Gives back a node whose element was already moved out,
+    /// deallocating it if the pool is full.
+    ///
+    /// # Safety
+    /// `node` must have been allocated as a `Box<Node<T>, A>` and must not be used again.
+    pub(super) unsafe fn recycle<A: Allocator>(&mut self, node: NonNull<Node<T>>, alloc: &A) {
+        if self.len < self.capacity {
+            unsafe { self.push(node) };
+        } else {
+            unsafe { deallocate(node, alloc) };
+        }
+    }
+
+    /// Deallocates every pooled node.
+    pub(super) fn shrink<A: Allocator>(&mut self, alloc: &A) {
+        while let Some(node) = self.free {
+            unsafe {
+                self.free = (*node.as_ptr()).next;
+                deallocate(node, alloc);
+            }
+        }
+        self.len = 0;
+    }
+
+    unsafe fn push(&mut self, node: NonNull<Node<T>>) {
+        // Only `next` is written, the rest of the node stays uninitialized.
+        unsafe { (*node.as_ptr()).next = self.free };
+        self.free = Some(node);
+        self.len += 1;
+    }
+}
+
+/// # Safety
+/// `node` must have been allocated as a `Box<Node<T>, A>` and its element must
+/// be moved out or uninitialized.
+unsafe fn deallocate<T, A: Allocator>(node: NonNull<Node<T>>, alloc: &A) {
+    drop(unsafe { Box::from_raw_in(node.as_ptr().cast::<MaybeUninit<Node<T>>>(), alloc) });
+}
+
+impl<T> LinkedList<T> {
+    /// Creates an empty `LinkedList` that keeps up to `capacity` popped nodes
+    /// in a pool and reuses them for later pushes instead of going to the
+    /// allocator.
+    ///
+    /// The pool is a plain free-list: it starts with `capacity` nodes, and
+    /// nodes popped while it is full are deallocated. Each node is still
+    /// allocated on its own, so the pool only saves trips to the allocator.
+    /// Clones and the lists returned by [`split_off`](LinkedList::split_off)
+    /// pool their nodes too, starting out with an empty pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn with_node_pool(capacity: usize) -> Self {
+        Self::with_node_pool_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    /// Like [`with_node_pool`](LinkedList::with_node_pool), but the nodes
+    /// are allocated in `alloc`.
+    pub fn with_node_pool_in(capacity: usize, alloc: A) -> Self {
+        assert!(capacity > 0, "node pool capacity must be greater than 0");
+        let mut list = Self::new_in(alloc);
+        list.pool = NodePool::with_capacity(capacity);
+        list.pool.fill(&list.alloc);
+        list
+    }
+
+    /// Returns the number of nodes waiting in the pool.
+    pub fn pool_len(&self) -> usize {
+        self.pool.len()
+    }
+
+    /// Deallocates all the nodes waiting in the pool.
+    pub fn shrink_pool(&mut self) {
+        self.pool.shrink(&self.alloc);
+    }
+}
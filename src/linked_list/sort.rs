@@ -0,0 +1,209 @@
+use super::{Link, LinkedList, Node};
+use std::alloc::Allocator;
+use std::cmp::Ordering;
+use std::ptr::NonNull;
+
+impl<T, A: Allocator + Clone> LinkedList<T, A> {
+    /// Sorts the list.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*))
+    /// worst-case. It only relinks the nodes, so it never moves the elements or allocates.
+    ///
+    /// If the implementation of [`Ord`] for `T` panics, the list keeps all of its
+    /// elements in an unspecified order.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list with a comparison function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*n* \* log(*n*))
+    /// worst-case. It only relinks the nodes, so it never moves the elements or allocates.
+    ///
+    /// If `compare` panics, the list keeps all of its elements in an unspecified order.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut sort = MergeSort::new(self);
+
+        // Bottom-up merge sort: bins[i] is either empty or holds a sorted run
+        // of exactly 2^i nodes, and runs in higher bins precede the ones in lower bins.
+        while let Some(node) = sort.input {
+            unsafe {
+                sort.input = (*node.as_ptr()).next.take();
+            }
+            sort.carry = Some(node);
+
+            for i in 0..BINS {
+                match sort.bins[i].take() {
+                    None => {
+                        sort.bins[i] = sort.carry.take();
+                        break;
+                    }
+                    Some(bin) => {
+                        let carry = sort.carry.take();
+                        sort.carry = sort.merge(Some(bin), carry, &mut compare);
+                    }
+                }
+            }
+        }
+
+        for i in 0..BINS {
+            if let Some(bin) = sort.bins[i].take() {
+                let carry = sort.carry.take();
+                sort.carry = sort.merge(Some(bin), carry, &mut compare);
+            }
+        }
+        // MergeSort relinks the sorted run back into the list on drop.
+    }
+
+    /// Sorts the list with a key extraction function.
+    ///
+    /// This sort is stable (i.e., does not reorder equal elements) and *O*(*m* \* *n* \* log(*n*))
+    /// worst-case, where the key function is *O*(*m*).
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Merges the sorted `other` list into this sorted list.
+    ///
+    /// Equal elements from `self` come before the ones from `other`. After this
+    /// operation, `other` becomes empty. If either list is not sorted the
+    /// resulting order is unspecified, but no elements are lost.
+    ///
+    /// This operation should compute in *O*(*n* + *m*) time and *O*(1) memory.
+    pub fn merge(&mut self, other: &mut Self)
+    where
+        T: Ord,
+    {
+        let other_head = other.head.take();
+        other.tail = None;
+        self.len += std::mem::replace(&mut other.len, 0);
+
+        let mut sort = MergeSort::new(self);
+        let input = sort.input.take();
+        sort.carry = sort.merge(input, other_head, &mut T::cmp);
+    }
+}
+
+const BINS: usize = usize::BITS as usize;
+
+/// The state of an in-progress merge sort over the nodes of a list.
+///
+/// Every chain here is terminated by a `None` in `next` and the `prev` links
+/// are ignored, so whenever `compare` is called each node belongs to exactly
+/// one chain. On drop, even when unwinding from a panicking `compare`, the
+/// chains are concatenated back into the list and the `prev` links are restored.
+struct MergeSort<'a, T, A: Allocator + Clone> {
+    list: &'a mut LinkedList<T, A>,
+    /// Nodes not yet visited
+    input: Link<T>,
+    /// Sorted runs
+    bins: [Link<T>; BINS],
+    /// The run being carried into the bins
+    carry: Link<T>,
+    /// Remaining runs of the merge in progress
+    left: Link<T>,
+    right: Link<T>,
+    /// Output of the merge in progress
+    out_head: Link<T>,
+    out_tail: Link<T>,
+}
+
+impl<'a, T, A: Allocator + Clone> MergeSort<'a, T, A> {
+    fn new(list: &'a mut LinkedList<T, A>) -> Self {
+        let input = list.head;
+        Self {
+            list,
+            input,
+            bins: [None; BINS],
+            carry: None,
+            left: None,
+            right: None,
+            out_head: None,
+            out_tail: None,
+        }
+    }
+
+    /// Merges two sorted runs, taking from `left` on ties.
+    fn merge<F>(&mut self, left: Link<T>, right: Link<T>, compare: &mut F) -> Link<T>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.left = left;
+        self.right = right;
+
+        while let (Some(l), Some(r)) = (self.left, self.right) {
+            // This method takes care not to create mutable references to whole nodes,
+            // to maintain validity of aliasing pointers into `element`.
+            unsafe {
+                // Take from the right only if it's strictly less, to keep the sort stable.
+                let node = if compare(&(*l.as_ptr()).element, &(*r.as_ptr()).element)
+                    == Ordering::Greater
+                {
+                    self.right = (*r.as_ptr()).next.take();
+                    r
+                } else {
+                    self.left = (*l.as_ptr()).next.take();
+                    l
+                };
+                self.push_out(node);
+            }
+        }
+
+        // At most one of them is non-empty, so just link it to the output.
+        if let Some(rest) = self.left.take().or(self.right.take()) {
+            unsafe { self.push_out(rest) };
+        }
+        self.out_tail = None;
+        self.out_head.take()
+    }
+
+    /// # Safety
+    /// `node` must be the head of a chain that is not referenced by any other field.
+    unsafe fn push_out(&mut self, node: NonNull<Node<T>>) {
+        match self.out_tail {
+            None => self.out_head = Some(node),
+            Some(tail) => unsafe { (*tail.as_ptr()).next = Some(node) },
+        }
+        self.out_tail = Some(node);
+    }
+}
+
+impl<T, A: Allocator + Clone> Drop for MergeSort<'_, T, A> {
+    fn drop(&mut self) {
+        let chains = [self.out_head, self.left, self.right, self.carry]
+            .into_iter()
+            .chain(self.bins.iter().rev().copied())
+            .chain([self.input]);
+
+        let mut head: Link<T> = None;
+        let mut tail: Link<T> = None;
+        for chain in chains {
+            let mut link = chain;
+            while let Some(node) = link {
+                // Not creating new mutable (unique!) references overlapping `element`.
+                unsafe {
+                    (*node.as_ptr()).prev = tail;
+                    match tail {
+                        None => head = Some(node),
+                        Some(tail) => (*tail.as_ptr()).next = Some(node),
+                    }
+                    tail = Some(node);
+                    link = (*node.as_ptr()).next;
+                }
+            }
+        }
+
+        self.list.head = head;
+        self.list.tail = tail;
+    }
+}
@@ -0,0 +1,76 @@
+/// Creates a [`serial::Vec`](crate::Vec) containing the arguments.
+///
+/// Like `std::vec!`, there are two forms of this macro:
+///
+/// - Create a `Vec` containing a given list of elements:
+///
+/// ```
+/// let v = serial::vec![1, 2, 3];
+/// assert_eq!(v[0], 1);
+/// assert_eq!(v[1], 2);
+/// assert_eq!(v[2], 3);
+/// ```
+///
+/// - Create a `Vec` from a given element and size:
+///
+/// ```
+/// let v = serial::vec![1; 3];
+/// assert_eq!(&*v, &[1, 1, 1]);
+/// ```
+///
+/// The second form requires the element to implement [`Clone`].
+#[macro_export]
+macro_rules! vec {
+    () => (
+        $crate::Vec::new()
+    );
+    ($elem:expr; $n:expr) => (
+        $crate::vec::from_elem($elem, $n)
+    );
+    ($($x:expr),+ $(,)?) => (
+        <$crate::Vec<_> as ::core::iter::FromIterator<_>>::from_iter([$($x),+])
+    );
+}
+
+/// Declares an [`Adapter`](crate::intrusive::Adapter) that links elements
+/// into an intrusive [`List`](crate::intrusive::List) through one of their
+/// [`Link`](crate::intrusive::Link) fields.
+///
+/// ```
+/// use serial::intrusive::{Link, List};
+///
+/// struct Connection {
+///     id: u32,
+///     idle: Link,
+/// }
+///
+/// serial::intrusive_adapter!(IdleAdapter = Connection { idle });
+///
+/// let conn = Box::pin(Connection { id: 7, idle: Link::new() });
+/// let mut idle = List::<IdleAdapter>::new();
+/// idle.push_back(conn.as_ref());
+/// assert_eq!(idle.iter().map(|c| c.id).collect::<Vec<_>>(), [7]);
+/// ```
+#[macro_export]
+macro_rules! intrusive_adapter {
+    ($vis:vis $name:ident = $value:ty { $field:ident }) => {
+        $vis struct $name;
+
+        unsafe impl $crate::intrusive::Adapter for $name {
+            type Value = $value;
+
+            unsafe fn get_link(
+                value: *const $value,
+            ) -> ::core::ptr::NonNull<$crate::intrusive::Link> {
+                let link = unsafe { &raw const (*value).$field };
+                unsafe { ::core::ptr::NonNull::new_unchecked(link.cast_mut()) }
+            }
+
+            unsafe fn get_value(
+                link: ::core::ptr::NonNull<$crate::intrusive::Link>,
+            ) -> ::core::ptr::NonNull<$value> {
+                unsafe { link.byte_sub(::core::mem::offset_of!($value, $field)).cast() }
+            }
+        }
+    };
+}
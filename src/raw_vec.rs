@@ -1,4 +1,4 @@
-use std::alloc::{Allocator, Layout};
+use std::alloc::{Allocator, Global, Layout};
 use std::marker::PhantomData;
 use std::mem;
 use std::ptr::NonNull;
@@ -7,24 +7,56 @@ use crate::try_reserve_error::handle_reserve;
 use crate::TryReserveError;
 use crate::TryReserveErrorKind::*;
 
-pub(crate) struct RawVec<T> {
+pub(crate) struct RawVec<T, A: Allocator = Global> {
     ptr: NonNull<T>,
     cap: usize,
+    alloc: A,
     _pd: PhantomData<T>,
 }
 
-// Direct API
-impl<T> RawVec<T> {
+unsafe impl<T: Send, A: Allocator + Send> Send for RawVec<T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for RawVec<T, A> {}
+
+impl<T> RawVec<T, Global> {
     pub(crate) const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+}
+
+// Direct API
+impl<T, A: Allocator> RawVec<T, A> {
+    pub(crate) const fn new_in(alloc: A) -> Self {
         Self {
             ptr: NonNull::dangling(),
             cap: 0,
+            alloc,
             _pd: PhantomData,
         }
     }
 
-    pub(crate) fn with_capacity(capacity: usize) -> Self {
-        handle_reserve(Self::try_allocate(capacity))
+    pub(crate) fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        handle_reserve(Self::try_allocate_in(capacity, alloc))
+    }
+
+    pub(crate) fn try_with_capacity_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
+        Self::try_allocate_in(capacity, alloc)
+    }
+
+    /// # Safety
+    ///  `ptr` must be allocated via `alloc` with the layout of `capacity` elements of `T`
+    ///  (or be dangling if `capacity == 0` or `T` is zero-sized).
+    pub(crate) unsafe fn from_raw_parts_in(ptr: *mut T, capacity: usize, alloc: A) -> Self {
+        Self {
+            ptr: unsafe { NonNull::new_unchecked(ptr) },
+            cap: capacity,
+            alloc,
+            _pd: PhantomData,
+        }
     }
 
     pub(crate) fn capacity(&self) -> usize {
@@ -38,13 +70,21 @@ impl<T> RawVec<T> {
     pub(crate) fn ptr(&self) -> *mut T {
         self.ptr.as_ptr()
     }
+
+    pub(crate) fn allocator(&self) -> &A {
+        &self.alloc
+    }
 }
 
 // Reserve API
-impl<T> RawVec<T> {
+impl<T, A: Allocator> RawVec<T, A> {
     pub(crate) fn reserve(&mut self, len: usize, additional: usize) {
         #[cold]
-        fn do_reserve_and_handle<T>(slf: &mut RawVec<T>, len: usize, additional: usize) {
+        fn do_reserve_and_handle<T, A: Allocator>(
+            slf: &mut RawVec<T, A>,
+            len: usize,
+            additional: usize,
+        ) {
             handle_reserve(slf.grow_amortized(len, additional));
         }
 
@@ -53,13 +93,86 @@ impl<T> RawVec<T> {
         }
     }
 
+    pub(crate) fn reserve_exact(&mut self, len: usize, additional: usize) {
+        handle_reserve(self.try_reserve_exact(len, additional));
+    }
+
     pub(crate) fn reserve_to_push(&mut self, len: usize) {
         handle_reserve(self.grow_amortized(len, 1));
     }
+
+    pub(crate) fn try_reserve(
+        &mut self,
+        len: usize,
+        additional: usize,
+    ) -> Result<(), TryReserveError> {
+        if self.needs_to_grow(len, additional) {
+            self.grow_amortized(len, additional)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn try_reserve_exact(
+        &mut self,
+        len: usize,
+        additional: usize,
+    ) -> Result<(), TryReserveError> {
+        if self.needs_to_grow(len, additional) {
+            self.grow_exact(len, additional)?;
+        }
+        Ok(())
+    }
+}
+
+// Shrinking
+impl<T, A: Allocator> RawVec<T, A> {
+    /// Shrinks the buffer down to the specified capacity. If the given amount
+    /// is 0, actually completely deallocates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given amount is *larger* than the current capacity.
+    pub(crate) fn shrink_to_fit(&mut self, cap: usize) {
+        handle_reserve(self.shrink(cap));
+    }
+
+    fn shrink(&mut self, cap: usize) -> Result<(), TryReserveError> {
+        assert!(
+            cap <= self.capacity(),
+            "Tried to shrink to a larger capacity"
+        );
+
+        let (ptr, layout) = if let Some(mem) = self.current_memory() {
+            mem
+        } else {
+            return Ok(());
+        };
+
+        if cap == 0 {
+            unsafe { self.alloc.deallocate(ptr, layout) };
+            self.ptr = NonNull::dangling();
+            self.cap = 0;
+        } else {
+            let ptr = unsafe {
+                // `Layout::array` cannot overflow here because it would have
+                // overflowed earlier when capacity was larger.
+                let new_size = mem::size_of::<T>() * cap;
+                let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+                self.alloc
+                    .shrink(ptr, layout, new_layout)
+                    .map_err(|_| AllocError { layout: new_layout })?
+            };
+            // SAFETY: if the allocation is valid, then the capacity is too
+            unsafe {
+                self.set_ptr_and_cap(ptr, cap);
+            }
+        }
+        Ok(())
+    }
 }
 
 // Growing
-impl<T> RawVec<T> {
+impl<T, A: Allocator> RawVec<T, A> {
     const MIN_NON_ZERO_CAP: usize = if mem::size_of::<T>() == 1 {
         8
     } else if mem::size_of::<T>() <= 1024 {
@@ -98,9 +211,9 @@ impl<T> RawVec<T> {
         self.cap = cap;
     }
 
-    fn try_allocate(capacity: usize) -> Result<Self, TryReserveError> {
-        if mem::size_of::<T>() == 0 {
-            return Ok(Self::new());
+    fn try_allocate_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
+        if mem::size_of::<T>() == 0 || capacity == 0 {
+            return Ok(Self::new_in(alloc));
         }
 
         let layout = match Layout::array::<T>(capacity) {
@@ -110,7 +223,7 @@ impl<T> RawVec<T> {
 
         alloc_guard(layout.size())?;
 
-        let result = std::alloc::System.allocate(layout);
+        let result = alloc.allocate(layout);
         let ptr = match result {
             Ok(ptr) => ptr,
             Err(_) => return Err(AllocError { layout }.into()),
@@ -122,6 +235,7 @@ impl<T> RawVec<T> {
         Ok(Self {
             ptr: ptr.cast(),
             cap: capacity,
+            alloc,
             _pd: PhantomData,
         })
     }
@@ -138,6 +252,22 @@ impl<T> RawVec<T> {
         let cap = std::cmp::max(self.cap * 2, required_cap);
         let cap = std::cmp::max(Self::MIN_NON_ZERO_CAP, cap);
 
+        self.finish_grow(cap)
+    }
+
+    fn grow_exact(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        if mem::size_of::<T>() == 0 {
+            // Since we return a capacity of `usize::MAX` when the type size is
+            // 0, getting to here necessarily means the `RawVec` is overfull.
+            return Err(CapacityOverflow.into());
+        }
+
+        let cap = len.checked_add(additional).ok_or(CapacityOverflow)?;
+
+        self.finish_grow(cap)
+    }
+
+    fn finish_grow(&mut self, cap: usize) -> Result<(), TryReserveError> {
         let new_layout = Layout::array::<T>(cap).map_err(|_| CapacityOverflow)?;
         alloc_guard(new_layout.size())?;
 
@@ -147,10 +277,10 @@ impl<T> RawVec<T> {
                 unsafe {
                     // The allocator checks for alignment equality
                     // hint::assert_unchecked(old_layout.align() == new_layout.align());
-                    std::alloc::System.grow(ptr, old_layout, new_layout)
+                    self.alloc.grow(ptr, old_layout, new_layout)
                 }
             } else {
-                std::alloc::System.allocate(new_layout)
+                self.alloc.allocate(new_layout)
             })
             .map_err(|_| AllocError { layout: new_layout }.into());
 
@@ -165,10 +295,10 @@ impl<T> RawVec<T> {
     }
 }
 
-unsafe impl<#[may_dangle] T> Drop for RawVec<T> {
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for RawVec<T, A> {
     fn drop(&mut self) {
         if let Some((ptr, layout)) = self.current_memory() {
-            unsafe { std::alloc::System.deallocate(ptr, layout) }
+            unsafe { self.alloc.deallocate(ptr, layout) }
         }
     }
 }
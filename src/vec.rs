@@ -1,8 +1,23 @@
 use crate::raw_vec::RawVec;
+use crate::TryReserveError;
+use std::alloc::{Allocator, Global};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem::ManuallyDrop;
+use std::ops::RangeBounds;
 use std::ptr;
 
-pub struct Vec<T> {
-    buf: RawVec<T>,
+mod into_iter;
+pub use into_iter::IntoIter;
+
+mod drain;
+pub use drain::Drain;
+
+mod partial_eq;
+
+pub struct Vec<T, A: Allocator = Global> {
+    buf: RawVec<T, A>,
     len: usize,
 }
 
@@ -21,6 +36,54 @@ impl<T> Vec<T> {
         }
     }
 
+    pub fn try_with_capacity(capacity: usize) -> Result<Self, TryReserveError> {
+        Self::try_with_capacity_in(capacity, Global)
+    }
+
+    /// # Safety
+    ///  See [`Vec::from_raw_parts_in`].
+    pub unsafe fn from_raw_parts(ptr: *mut T, length: usize, capacity: usize) -> Self {
+        unsafe { Self::from_raw_parts_in(ptr, length, capacity, Global) }
+    }
+}
+
+impl<T, A: Allocator> Vec<T, A> {
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            buf: RawVec::new_in(alloc),
+            len: 0,
+        }
+    }
+
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            buf: RawVec::with_capacity_in(capacity, alloc),
+            len: 0,
+        }
+    }
+
+    pub fn try_with_capacity_in(capacity: usize, alloc: A) -> Result<Self, TryReserveError> {
+        Ok(Self {
+            buf: RawVec::try_with_capacity_in(capacity, alloc)?,
+            len: 0,
+        })
+    }
+
+    /// # Safety
+    ///  `ptr` must be allocated via `alloc` with the layout of `capacity` elements of `T`,
+    ///  `length` must be less than or equal to `capacity`,
+    ///  and the first `length` elements must be initialized.
+    pub unsafe fn from_raw_parts_in(ptr: *mut T, length: usize, capacity: usize, alloc: A) -> Self {
+        Self {
+            buf: unsafe { RawVec::from_raw_parts_in(ptr, capacity, alloc) },
+            len: length,
+        }
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.buf.allocator()
+    }
+
     pub fn is_empty(&self) -> bool {
         self.len == 0
     }
@@ -52,26 +115,55 @@ impl<T> Vec<T> {
     }
 }
 
+#[doc(hidden)]
+pub fn from_elem<T: Clone>(elem: T, n: usize) -> Vec<T> {
+    let mut v = Vec::with_capacity(n);
+    v.extend_with(n, elem);
+    v
+}
+
 impl<T> Default for Vec<T> {
     fn default() -> Vec<T> {
         Vec::new()
     }
 }
 
-impl<T> Vec<T> {
+impl<T, A: Allocator> Vec<T, A> {
     pub fn reserve(&mut self, additional: usize) {
         self.buf.reserve(self.len, additional);
     }
 
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.buf.reserve_exact(self.len, additional);
+    }
+
+    /// Shrinks the capacity of the vector as much as possible.
+    pub fn shrink_to_fit(&mut self) {
+        // The capacity is never less than the length, and there's nothing to do when
+        // they are equal, so we can avoid the panic case in `RawVec::shrink_to_fit`
+        // by only calling it with a greater capacity.
+        if self.capacity() > self.len {
+            self.buf.shrink_to_fit(self.len);
+        }
+    }
+
+    /// Shrinks the capacity of the vector with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the length
+    /// and the supplied value.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        if self.capacity() > min_capacity {
+            self.buf
+                .shrink_to_fit(std::cmp::max(self.len, min_capacity));
+        }
+    }
+
     pub fn push(&mut self, value: T) {
         if self.len == self.buf.capacity() {
             self.buf.reserve_to_push(self.len);
         }
-        unsafe {
-            let end = self.as_mut_ptr().add(self.len);
-            ptr::write(end, value);
-            self.len += 1;
-        }
+        // SAFETY: we just made room for one more element
+        unsafe { self.push_within_capacity(value) };
     }
 
     pub fn pop(&mut self) -> Option<T> {
@@ -98,25 +190,38 @@ impl<T> Vec<T> {
             self.buf.reserve_to_push(self.len);
         }
 
+        // SAFETY: index <= len and we just made room for one more element
+        unsafe { self.insert_within_capacity(index, value) };
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        #[cold]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("removal index (is {index}) should be < len (is {len})");
+        }
+
+        if index >= self.len {
+            assert_failed(index, self.len);
+        }
         unsafe {
             // infallible
-            // The spot to put the new value
+            let value;
             {
+                // the place we are taking from.
                 let p = self.as_mut_ptr().add(index);
-                if index < self.len {
-                    // Shift everything over to make space. (Duplicating the
-                    // `index`th element into two consecutive places.)
-                    ptr::copy(p, p.add(1), self.len - index);
-                }
-                // Write it in, overwriting the first copy of the `index`th
-                // element.
-                ptr::write(p, value);
+                // copy it out, unsafely having a copy of the value on
+                // the stack and in the vector at the same time.
+                value = ptr::read(p);
+
+                // Shift everything down to fill in that spot.
+                ptr::copy(p.add(1), p, self.len - index - 1);
             }
-            self.len += 1;
+            self.len -= 1;
+            value
         }
     }
 
-    pub fn remove(&mut self, index: usize) -> T {
+    pub fn swap_remove(&mut self, index: usize) -> T {
         #[cold]
         fn assert_failed(index: usize, len: usize) -> ! {
             panic!("swap_remove index (is {index}) should be < len (is {len})");
@@ -137,6 +242,32 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Shortens the vector, keeping the first `len` elements and dropping
+    /// the rest. Has no effect if `len` is greater than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len > self.len {
+            return;
+        }
+        unsafe {
+            let remaining_len = self.len - len;
+            let s = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), remaining_len);
+            // Update len first, so a panicking destructor can't cause a double drop.
+            self.len = len;
+            ptr::drop_in_place(s);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.extend(other.iter().cloned());
+    }
+
     pub fn append(&mut self, other: &mut Self) {
         unsafe {
             self.append_elements(other.as_slice() as _);
@@ -144,8 +275,123 @@ impl<T> Vec<T> {
         }
     }
 
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buf.try_reserve(self.len, additional)
+    }
+
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buf.try_reserve_exact(self.len, additional)
+    }
+
+    /// Appends an element to the back of the vector, returning an error
+    /// instead of panicking or aborting if the allocation fails.
+    ///
+    /// On error the element is dropped and the vector is left unchanged.
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        if self.len == self.buf.capacity() {
+            self.buf.try_reserve(self.len, 1)?;
+        }
+        // SAFETY: we just made room for one more element
+        unsafe { self.push_within_capacity(value) };
+        Ok(())
+    }
+
+    /// Inserts an element at position `index`, returning an error
+    /// instead of panicking or aborting if the allocation fails.
+    ///
+    /// On error the element is dropped and the vector is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), TryReserveError> {
+        #[cold]
+        fn assert_failed(index: usize, len: usize) -> ! {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        if index > self.len {
+            assert_failed(index, self.len);
+        }
+
+        if self.len == self.buf.capacity() {
+            self.buf.try_reserve(self.len, 1)?;
+        }
+        // SAFETY: index <= len and we just made room for one more element
+        unsafe { self.insert_within_capacity(index, value) };
+        Ok(())
+    }
+
+    /// Removes the specified range from the vector in bulk, returning all
+    /// removed elements as an iterator. If the iterator is dropped before
+    /// being fully consumed, it drops the remaining removed elements.
+    ///
+    /// If the returned iterator is leaked (e.g. with `mem::forget`), the
+    /// vector is left with only the elements before the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if
+    /// the end point is greater than the length of the vector.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, A> {
+        let len = self.len;
+        let std::ops::Range { start, end } = std::slice::range(range, ..len);
+
+        unsafe {
+            // set self.vec length's to start, to be safe in case Drain is leaked
+            self.set_len(start);
+            Drain::new(self, start, end, len - end)
+        }
+    }
+
+    /// # Safety
+    ///  `len` must be less than capacity.
+    unsafe fn push_within_capacity(&mut self, value: T) {
+        unsafe {
+            let end = self.as_mut_ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+
+    /// # Safety
+    ///  `index` must be less than or equal to `len` and `len` must be less than capacity.
+    unsafe fn insert_within_capacity(&mut self, index: usize, value: T) {
+        unsafe {
+            // infallible
+            // The spot to put the new value
+            {
+                let p = self.as_mut_ptr().add(index);
+                if index < self.len {
+                    // Shift everything over to make space. (Duplicating the
+                    // `index`th element into two consecutive places.)
+                    ptr::copy(p, p.add(1), self.len - index);
+                }
+                // Write it in, overwriting the first copy of the `index`th
+                // element.
+                ptr::write(p, value);
+            }
+            self.len += 1;
+        }
+    }
+
+    /// Extend the vector by `n` clones of value.
+    fn extend_with(&mut self, n: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.reserve(n);
+        if n > 0 {
+            for _ in 1..n {
+                self.push(value.clone());
+            }
+            // We can write the last element directly without cloning needlessly
+            self.push(value);
+        }
+    }
+
     unsafe fn append_elements(&mut self, other: *const [T]) {
-        let count = unsafe { (*other).len() };
+        let count = other.len();
         self.reserve(count);
         unsafe {
             let end = self.as_mut_ptr().add(self.len);
@@ -155,7 +401,7 @@ impl<T> Vec<T> {
     }
 }
 
-unsafe impl<#[may_dangle] T> Drop for Vec<T> {
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for Vec<T, A> {
     fn drop(&mut self) {
         unsafe {
             // use drop for [T]
@@ -167,7 +413,7 @@ unsafe impl<#[may_dangle] T> Drop for Vec<T> {
     }
 }
 
-impl<T> std::ops::Deref for Vec<T> {
+impl<T, A: Allocator> std::ops::Deref for Vec<T, A> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
@@ -175,8 +421,110 @@ impl<T> std::ops::Deref for Vec<T> {
     }
 }
 
-impl<T> std::ops::DerefMut for Vec<T> {
+impl<T, A: Allocator> std::ops::DerefMut for Vec<T, A> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         unsafe { std::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
     }
 }
+
+impl<T: Clone, A: Allocator + Clone> Clone for Vec<T, A> {
+    fn clone(&self) -> Self {
+        let mut vec = Vec::with_capacity_in(self.len, self.allocator().clone());
+        vec.extend_from_slice(self);
+        vec
+    }
+
+    /// Overwrites the contents of `self` with a clone of the contents of `source`,
+    /// reusing the existing allocation where possible.
+    fn clone_from(&mut self, source: &Self) {
+        // drop anything that will not be overwritten
+        self.truncate(source.len());
+
+        // self.len <= source.len due to the truncate above, so the
+        // slices here are always in-bounds.
+        let (init, tail) = source.split_at(self.len());
+
+        // reuse the contained values' allocations/resources.
+        self.clone_from_slice(init);
+        self.extend_from_slice(tail);
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Vec<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for Vec<T, A> {}
+
+impl<T: PartialOrd, A1: Allocator, A2: Allocator> PartialOrd<Vec<T, A2>> for Vec<T, A1> {
+    fn partial_cmp(&self, other: &Vec<T, A2>) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&**self, &**other)
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for Vec<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&**self, &**other)
+    }
+}
+
+impl<T: Hash, A: Allocator> Hash for Vec<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&**self, state)
+    }
+}
+
+impl<T> FromIterator<T> for Vec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Vec::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for Vec<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.reserve(lower);
+        iter.for_each(move |element| self.push(element));
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator> Extend<&'a T> for Vec<T, A> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for Vec<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let me = ManuallyDrop::new(self);
+        // SAFETY: `me` is never dropped, so the buffer and the first `len`
+        //  elements are moved into the iterator exactly once.
+        unsafe { IntoIter::new(ptr::read(&me.buf), me.len) }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a Vec<T, A> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut Vec<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
@@ -0,0 +1,132 @@
+use crate::Vec;
+use std::alloc::{Allocator, Global};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ptr::{self, NonNull};
+
+/// A draining iterator for `Vec<T>`.
+///
+/// This `struct` is created by [`Vec::drain`].
+pub struct Drain<'a, T, A: Allocator = Global> {
+    /// Index of the next element to yield from the front
+    idx: usize,
+    /// Index one past the next element to yield from the back
+    end: usize,
+    /// Index of tail to preserve
+    tail_start: usize,
+    /// Length of tail
+    tail_len: usize,
+    vec: NonNull<Vec<T, A>>,
+    _pd: PhantomData<&'a T>,
+}
+
+unsafe impl<T: Send, A: Allocator + Send> Send for Drain<'_, T, A> {}
+
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Drain<'_, T, A> {}
+
+impl<'a, T, A: Allocator> Drain<'a, T, A> {
+    /// # Safety
+    ///  `vec.len()` must be already truncated to `start`, the elements
+    ///  in `start..end` must be initialized and owned by the drain, and
+    ///  `tail_len` initialized elements must follow them.
+    pub(super) unsafe fn new(
+        vec: &'a mut Vec<T, A>,
+        start: usize,
+        end: usize,
+        tail_len: usize,
+    ) -> Self {
+        Self {
+            idx: start,
+            end,
+            tail_start: end,
+            tail_len,
+            vec: NonNull::from(vec),
+            _pd: PhantomData,
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        unsafe {
+            let ptr = self.vec.as_ref().as_ptr().add(self.idx);
+            std::slice::from_raw_parts(ptr, self.end - self.idx)
+        }
+    }
+
+    pub fn allocator(&self) -> &A {
+        unsafe { self.vec.as_ref().allocator() }
+    }
+}
+
+impl<T, A: Allocator> Iterator for Drain<'_, T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            // SAFETY: idx < end, so the element is initialized and we
+            //  never read it again because idx moves past it.
+            let value = unsafe { ptr::read(self.vec.as_ref().as_ptr().add(self.idx)) };
+            self.idx += 1;
+            Some(value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.idx;
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for Drain<'_, T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.idx == self.end {
+            None
+        } else {
+            self.end -= 1;
+            // SAFETY: end was > idx, so the element is initialized and we
+            //  never read it again because end moved before it.
+            unsafe { Some(ptr::read(self.vec.as_ref().as_ptr().add(self.end))) }
+        }
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> FusedIterator for Drain<'_, T, A> {}
+
+impl<T, A: Allocator> Drop for Drain<'_, T, A> {
+    fn drop(&mut self) {
+        /// Moves back the un-`Drain`ed elements to restore the original `Vec`,
+        /// even if dropping one of the remaining elements panics.
+        struct DropGuard<'r, 'a, T, A: Allocator>(&'r mut Drain<'a, T, A>);
+
+        impl<T, A: Allocator> Drop for DropGuard<'_, '_, T, A> {
+            fn drop(&mut self) {
+                if self.0.tail_len > 0 {
+                    unsafe {
+                        let source_vec = self.0.vec.as_mut();
+                        // memmove back untouched tail, update to new length
+                        let start = source_vec.len();
+                        let tail = self.0.tail_start;
+                        if tail != start {
+                            let src = source_vec.as_ptr().add(tail);
+                            let dst = source_vec.as_mut_ptr().add(start);
+                            ptr::copy(src, dst, self.0.tail_len);
+                        }
+                        source_vec.set_len(start + self.0.tail_len);
+                    }
+                }
+            }
+        }
+
+        let (idx, end) = (self.idx, self.end);
+        // Mark the rest as yielded first, so a panic below can't drop them twice.
+        self.idx = end;
+        let guard = DropGuard(self);
+        unsafe {
+            let ptr = guard.0.vec.as_mut().as_mut_ptr().add(idx);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(ptr, end - idx));
+        }
+    }
+}
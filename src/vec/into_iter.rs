@@ -0,0 +1,89 @@
+use crate::raw_vec::RawVec;
+use std::alloc::{Allocator, Global};
+use std::iter::FusedIterator;
+use std::ptr;
+
+/// An iterator that moves out of a vector.
+///
+/// This `struct` is created by the `into_iter` method on [`Vec`](crate::Vec).
+pub struct IntoIter<T, A: Allocator = Global> {
+    buf: RawVec<T, A>,
+    start: usize,
+    end: usize,
+}
+
+impl<T, A: Allocator> IntoIter<T, A> {
+    /// # Safety
+    ///  The first `len` elements of `buf` must be initialized
+    ///  and this iterator takes ownership of them.
+    pub(super) unsafe fn new(buf: RawVec<T, A>, len: usize) -> Self {
+        Self {
+            buf,
+            start: 0,
+            end: len,
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr().add(self.start), self.len()) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.buf.ptr().add(self.start), self.len()) }
+    }
+
+    pub fn allocator(&self) -> &A {
+        self.buf.allocator()
+    }
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            // SAFETY: start < end, so the element is initialized and we
+            //  never read it again because start moves past it.
+            let value = unsafe { ptr::read(self.buf.ptr().add(self.start)) };
+            self.start += 1;
+            Some(value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            // SAFETY: end was > start, so the element is initialized and we
+            //  never read it again because end moved before it.
+            unsafe { Some(ptr::read(self.buf.ptr().add(self.end))) }
+        }
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+unsafe impl<#[may_dangle] T, A: Allocator> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        // Drop the elements that were not yielded. If one of the destructors
+        // panics, drop_in_place still drops the rest, and RawVec frees the
+        // buffer when the fields are dropped during unwinding.
+        unsafe { ptr::drop_in_place(self.as_mut_slice() as *mut [T]) }
+    }
+}
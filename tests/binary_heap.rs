@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 use utils::rand::RngCore;
+use utils::OrderedDropMock;
 
 #[test]
 fn test_basic() {
@@ -21,49 +22,356 @@ fn test_basic() {
 }
 
 #[test]
-#[allow(dead_code)]
-fn assert_covariance() {
-    fn a<'a>(x: serial::BinaryHeap<&'static str>) -> serial::BinaryHeap<&'a str> {
-        x
+fn test_from_iter_extend() {
+    let mut heap: serial::BinaryHeap<i32> = [3, 1, 2].into_iter().collect();
+    heap.extend([5, 4]);
+    heap.extend(&[0, 6]);
+    assert_eq!(heap.len(), 7);
+    for i in (0..7).rev() {
+        assert_eq!(heap.pop(), Some(i));
     }
+    assert_eq!(heap.pop(), None);
 }
 
 #[test]
-fn test_dropck() {
-    let mock = utils::DropMock::new();
+fn test_clone() {
+    let heap: serial::BinaryHeap<i32> = [3, 1, 2].into_iter().collect();
+    let mut other = heap.clone();
+    assert_eq!(format!("{heap:?}"), format!("{other:?}"));
+    other.push(4);
+    other.clone_from(&heap);
+    assert_eq!(other.len(), 3);
+    assert_eq!(other.pop(), Some(3));
+}
+
+#[test]
+fn test_try_push() {
+    let mut heap = serial::BinaryHeap::new();
+    for i in [3, 4, 1, 2, 5] {
+        assert!(heap.try_push(i).is_ok());
+    }
+    assert_eq!(heap.pop(), Some(5));
+    assert_eq!(heap.pop(), Some(4));
+}
+
+#[test]
+fn test_try_push_alloc_error() {
+    let alloc = utils::AllocMock::new();
+    let mut heap = serial::BinaryHeap::new_in(alloc.clone());
+    heap.push(1);
+    while heap.len() < heap.capacity() {
+        heap.push(0);
+    }
+    let len = heap.len();
+
+    alloc.fail_next_alloc();
+    assert!(matches!(
+        heap.try_push(2).map_err(|e| e.kind()),
+        Err(serial::TryReserveErrorKind::AllocError { .. })
+    ));
+    assert_eq!(heap.len(), len);
+    assert_eq!(heap.peek(), Some(&1));
+    assert_eq!(alloc.alloc_cnt(), 1);
+}
+
+#[test]
+fn test_shrink() {
+    let mut heap = serial::BinaryHeap::with_capacity(100);
+    for i in [3, 4, 1, 2, 5] {
+        heap.push(i);
+    }
+    heap.shrink_to(10);
+    assert_eq!(heap.capacity(), 10);
+    heap.shrink_to_fit();
+    assert_eq!(heap.capacity(), 5);
+    heap.reserve_exact(5);
+    assert_eq!(heap.capacity(), 10);
+    assert_eq!(heap.pop(), Some(5));
+}
+
+#[test]
+fn test_allocator() {
+    let alloc = utils::AllocMock::new();
     {
-        let mut heap = serial::BinaryHeap::<OrderedDropMock>::new();
-        let mut random_range = utils::rand::thread_rng();
-        for _ in 0..10 {
-            heap.push(OrderedDropMock {
-                0: random_range.next_u32(),
-                1: mock.clone(),
-            });
+        let mut heap = serial::BinaryHeap::new_in(alloc.clone());
+        for i in [3, 4, 1, 2, 5] {
+            heap.push(i);
         }
+        assert_eq!(heap.allocator().alloc_cnt(), 1);
+        assert_eq!(heap.pop(), Some(5));
+    }
+    assert_eq!(alloc.alloc_cnt(), 0);
+}
+
+#[test]
+fn test_allocator_comparator() {
+    let alloc = utils::AllocMock::new();
+    {
+        let mut heap: serial::BinaryHeap<i32, utils::AllocMock, _> =
+            serial::BinaryHeap::with_comparator_in(
+                serial::binary_heap::MinComparator,
+                alloc.clone(),
+            );
+        heap.extend([3, 4, 1, 2, 5]);
+        assert_eq!(heap.allocator().alloc_cnt(), 1);
+        assert_eq!(heap.pop(), Some(1));
+        assert_eq!(heap.into_sorted_vec(), [5, 4, 3, 2]);
     }
+    assert_eq!(alloc.alloc_cnt(), 0);
+}
+
+#[test]
+fn test_peek_mut() {
+    let mut heap: serial::BinaryHeap<i32> = [2, 4, 9, 3].into_iter().collect();
+    assert_eq!(heap.peek(), Some(&9));
+    {
+        let mut top = heap.peek_mut().unwrap();
+        assert_eq!(*top, 9);
+        *top = 1;
+    }
+    assert_eq!(heap.peek(), Some(&4));
+
+    // Not mutating keeps the heap as it is.
+    assert_eq!(*heap.peek_mut().unwrap(), 4);
+    assert_eq!(heap.len(), 4);
+
+    let mut top = heap.peek_mut().unwrap();
+    *top = 10;
+    assert_eq!(serial::binary_heap::PeekMut::pop(top), 10);
+    assert_eq!(heap.len(), 3);
+    assert_eq!(heap.pop(), Some(3));
+    assert_eq!(heap.pop(), Some(2));
+    assert_eq!(heap.pop(), Some(1));
+    assert!(heap.peek_mut().is_none());
+    assert_eq!(heap.peek(), None);
+}
+
+#[test]
+fn test_peek_mut_leak() {
+    let mut heap: serial::BinaryHeap<i32> = [2, 4, 9, 3].into_iter().collect();
+    let mut top = heap.peek_mut().unwrap();
+    *top = 0;
+    std::mem::forget(top);
+    // The other elements are leaked, but the heap stays valid.
+    assert_eq!(heap.len(), 1);
+    assert_eq!(heap.pop(), Some(0));
+}
+
+#[test]
+fn test_introspection() {
+    let mut heap: serial::BinaryHeap<i32> = [5, 1, 8, 3].into_iter().collect();
+    assert_eq!(heap.as_slice().len(), 4);
+    assert_eq!(heap.as_slice()[0], 8);
+
+    let mut items: Vec<_> = heap.iter().copied().collect();
+    items.sort();
+    assert_eq!(items, [1, 3, 5, 8]);
+    assert_eq!((&heap).into_iter().len(), 4);
+
+    let vec = heap.clone().into_vec();
+    assert_eq!(&*vec, heap.as_slice());
+    let vec: serial::Vec<i32> = heap.clone().into();
+    assert_eq!(&*vec, heap.as_slice());
+
+    let capacity = heap.capacity();
+    heap.clear();
+    assert!(heap.is_empty());
+    assert_eq!(heap.capacity(), capacity);
+}
+
+#[test]
+fn test_from_vec() {
+    let mut rng = utils::rand::thread_rng();
+    for len in [0, 1, 2, 3, 10, 100, 1000] {
+        let vec: serial::Vec<u32> = (0..len).map(|_| rng.next_u32() % 100).collect();
+        let mut expected: Vec<u32> = vec.iter().copied().collect();
+        expected.sort_by(|a, b| b.cmp(a));
+
+        let mut heap = serial::BinaryHeap::from(vec);
+        assert_eq!(heap.len(), len);
+        let popped: Vec<u32> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(popped, expected);
+    }
+
+    let mut heap = serial::BinaryHeap::from([3, 1, 4, 1, 5, 9, 2, 6]);
+    assert_eq!(heap.peek(), Some(&9));
+    assert_eq!(heap.len(), 8);
+    heap.push(7);
+    assert_eq!(heap.pop(), Some(9));
+    assert_eq!(heap.pop(), Some(7));
+}
+
+#[test]
+fn test_append() {
+    let mut rng = utils::rand::thread_rng();
+    for (len, other_len) in [
+        (0, 0),
+        (0, 5),
+        (5, 0),
+        (100, 3),
+        (3, 100),
+        (3000, 20),
+        (20, 20),
+    ] {
+        let mut heap: serial::BinaryHeap<u32> = (0..len).map(|_| rng.next_u32()).collect();
+        let mut other: serial::BinaryHeap<u32> = (0..other_len).map(|_| rng.next_u32()).collect();
+        let mut expected: Vec<u32> = heap.iter().chain(other.iter()).copied().collect();
+        expected.sort_by(|a, b| b.cmp(a));
+
+        heap.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(heap.len(), len + other_len);
+        let popped: Vec<u32> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(popped, expected);
+    }
+}
+
+#[test]
+fn test_append_comparator() {
+    type Cmp = fn(&i32, &i32) -> Ordering;
+    let mut max = serial::BinaryHeap::new_by(i32::cmp as Cmp);
+    let mut min = serial::BinaryHeap::new_by((|a: &i32, b: &i32| b.cmp(a)) as Cmp);
+    max.extend([1, 5]);
+    min.extend([2, 4, 3, 6]);
+
+    max.append(&mut min);
+    assert!(min.is_empty());
+    min.extend([8, 7]);
+    assert_eq!(min.pop(), Some(7));
+    let popped: Vec<i32> = std::iter::from_fn(|| max.pop()).collect();
+    assert_eq!(popped, [6, 5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn test_drain() {
+    let mut heap: serial::BinaryHeap<i32> = (0..10).collect();
+    let capacity = heap.capacity();
+    let mut drained: Vec<_> = heap.drain().collect();
+    drained.sort();
+    assert_eq!(drained, (0..10).collect::<Vec<_>>());
+    assert!(heap.is_empty());
+    assert_eq!(heap.capacity(), capacity);
+
+    let mock = utils::DropMock::new();
+    let mut heap: serial::BinaryHeap<_> =
+        (0..10).map(|i| OrderedDropMock(i, mock.clone())).collect();
+    assert_eq!(heap.drain().len(), 10);
+    assert_eq!(mock.drop_cnt(), 10);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_drain_sorted() {
+    let mut heap = serial::BinaryHeap::from([2, 7, 1, 8, 2, 8]);
+    let mut drain = heap.drain_sorted();
+    assert_eq!(drain.len(), 6);
+    assert_eq!(drain.next(), Some(8));
+    assert_eq!(drain.next(), Some(8));
+    assert_eq!(drain.len(), 4);
+    drop(drain);
+    assert!(heap.is_empty());
+
+    heap.extend([3, 1, 2]);
+    assert_eq!(heap.drain_sorted().collect::<Vec<_>>(), [3, 2, 1]);
+
+    let mock = utils::DropMock::new();
+    let mut heap: serial::BinaryHeap<_> =
+        (0..10).map(|i| OrderedDropMock(i, mock.clone())).collect();
+    assert_eq!(heap.drain_sorted().next().map(|x| x.0), Some(9));
     assert_eq!(mock.drop_cnt(), 10);
 }
 
-/// OrderedDropMock counts drops and ordered like u32.
-#[allow(dead_code)] // Compiler don't assume that dropping is use.
-struct OrderedDropMock(u32, utils::DropMock);
+#[test]
+fn test_into_sorted() {
+    let mut rng = utils::rand::thread_rng();
+    for len in [0, 1, 2, 3, 10, 100, 1000] {
+        let heap: serial::BinaryHeap<u32> = (0..len).map(|_| rng.next_u32() % 100).collect();
+        let mut expected: Vec<u32> = heap.iter().copied().collect();
+        expected.sort();
+
+        let clone = heap.clone();
+        let capacity = clone.capacity();
+        let sorted = clone.into_sorted_vec();
+        assert_eq!(sorted, expected);
+        assert_eq!(sorted.capacity(), capacity);
 
-impl PartialEq for OrderedDropMock {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0
+        let iter = heap.into_iter_sorted();
+        assert_eq!(iter.len(), len);
+        assert!(iter.eq(expected.into_iter().rev()));
     }
 }
 
-impl Eq for OrderedDropMock {}
+#[test]
+fn test_min_heap() {
+    let mut heap = serial::BinaryHeap::new_min();
+    heap.extend([3, 4, 1, 2, 5]);
+    assert_eq!(heap.peek(), Some(&1));
+    *heap.peek_mut().unwrap() = 6;
+    assert_eq!(heap.pop(), Some(2));
+    assert_eq!(heap.clone().into_sorted_vec(), [6, 5, 4, 3]);
+    assert_eq!(heap.drain_sorted().collect::<Vec<_>>(), [3, 4, 5, 6]);
+
+    let mut heap: serial::BinaryHeap<i32, _, serial::binary_heap::MinComparator> =
+        Default::default();
+    heap.push(1);
+    heap.push(0);
+    assert_eq!(heap.into_iter_sorted().collect::<Vec<_>>(), [0, 1]);
+}
+
+#[test]
+fn test_custom_order() {
+    let mut heap = serial::BinaryHeap::new_by(|a: &(i32, char), b| b.0.cmp(&a.0));
+    heap.extend([(2, 'b'), (1, 'a'), (3, 'c')]);
+    assert_eq!(heap.pop(), Some((1, 'a')));
+    assert_eq!(heap.pop(), Some((2, 'b')));
+
+    let mut heap = serial::BinaryHeap::new_by_key(|s: &&str| s.len());
+    heap.extend(["a", "abc", "ab"]);
+    assert_eq!(heap.drain_sorted().collect::<Vec<_>>(), ["abc", "ab", "a"]);
+
+    struct Modulo(u32);
+
+    impl serial::binary_heap::Compare<u32> for Modulo {
+        fn compare(&self, l: &u32, r: &u32) -> Ordering {
+            (l % self.0).cmp(&(r % self.0))
+        }
+    }
 
-impl PartialOrd for OrderedDropMock {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.0.partial_cmp(&other.0)
+    let vec: serial::Vec<u32> = (0..20).collect();
+    let mut heap = serial::BinaryHeap::from_vec_cmp(vec, Modulo(7));
+    assert_eq!(heap.comparator().0, 7);
+    for _ in 0..2 {
+        assert_eq!(heap.pop().map(|x| x % 7), Some(6));
     }
+    assert_eq!(heap.pop().map(|x| x % 7), Some(5));
 }
 
-impl Ord for OrderedDropMock {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.0.cmp(&other.0)
+#[test]
+#[allow(dead_code)]
+fn assert_covariance() {
+    fn a<'a>(x: serial::BinaryHeap<&'static str>) -> serial::BinaryHeap<&'a str> {
+        x
     }
 }
+
+#[test]
+fn assert_send_sync() {
+    utils::assert_send::<serial::BinaryHeap<i32>>();
+    utils::assert_sync::<serial::BinaryHeap<i32>>();
+
+    utils::assert_not_send!(serial::BinaryHeap<std::rc::Rc<i32>>);
+    utils::assert_not_sync!(serial::BinaryHeap<std::cell::Cell<i32>>);
+}
+
+#[test]
+fn test_dropck() {
+    let mock = utils::DropMock::new();
+    {
+        let mut heap = serial::BinaryHeap::<OrderedDropMock>::new();
+        let mut random_range = utils::rand::thread_rng();
+        for _ in 0..10 {
+            heap.push(OrderedDropMock(random_range.next_u32(), mock.clone()));
+        }
+    }
+    assert_eq!(mock.drop_cnt(), 10);
+}
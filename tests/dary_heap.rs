@@ -0,0 +1,108 @@
+use utils::rand::RngCore;
+
+fn check_random<const D: usize>() {
+    let mut rng = utils::rand::thread_rng();
+    for len in [0, 1, 2, 3, D, D + 1, 100, 1000] {
+        let vec: serial::Vec<u32> = (0..len).map(|_| rng.next_u32() % 100).collect();
+        let mut expected: Vec<u32> = vec.iter().copied().collect();
+        expected.sort_by(|a, b| b.cmp(a));
+
+        let mut heap = serial::DaryHeap::<_, D>::from(vec.clone());
+        assert_eq!(heap.len(), len);
+        let popped: Vec<u32> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(popped, expected);
+
+        let mut heap = serial::DaryHeap::<_, D>::new();
+        heap.extend(vec.iter());
+        assert_eq!(heap.peek(), expected.first());
+        let sorted: Vec<u32> = heap.into_sorted_vec().iter().rev().copied().collect();
+        assert_eq!(sorted, expected);
+    }
+}
+
+#[test]
+fn test_basic() {
+    let mut heap = serial::DaryHeap::<_, 3>::new();
+    assert!(heap.is_empty());
+    for x in [3, 4, 1, 2, 5] {
+        heap.push(x);
+    }
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.peek(), Some(&5));
+    for i in (1..6).rev() {
+        assert_eq!(heap.pop(), Some(i));
+    }
+    assert_eq!(heap.pop(), None);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_random() {
+    check_random::<2>();
+    check_random::<3>();
+    check_random::<4>();
+    check_random::<8>();
+    check_random::<16>();
+}
+
+#[test]
+fn test_interleaved() {
+    let mut rng = utils::rand::thread_rng();
+    let mut heap: serial::DaryHeap<u32> = serial::DaryHeap::new();
+    let mut expected = Vec::new();
+    for _ in 0..2000 {
+        if rng.next_u32().is_multiple_of(3) {
+            expected.sort();
+            assert_eq!(heap.pop(), expected.pop());
+        } else {
+            let x = rng.next_u32() % 1000;
+            heap.push(x);
+            expected.push(x);
+        }
+        assert_eq!(heap.len(), expected.len());
+    }
+}
+
+#[test]
+fn test_min_heap() {
+    let mut heap = serial::DaryHeap::<_, 4, _, _>::new_min();
+    heap.extend([5, 3, 8, 1, 9, 2]);
+    let popped: Vec<i32> = std::iter::from_fn(|| heap.pop()).collect();
+    assert_eq!(popped, [1, 2, 3, 5, 8, 9]);
+
+    let heap = serial::DaryHeap::<_, 4, _, _>::from_vec_cmp(
+        serial::vec![5, 3, 8, 1],
+        serial::binary_heap::KeyComparator(|x: &i32| -x),
+    );
+    assert_eq!(heap.peek(), Some(&1));
+}
+
+#[test]
+fn test_clone() {
+    let heap = serial::DaryHeap::<_, 4>::from([3, 1, 2]);
+    let mut clone = heap.clone();
+    clone.push(4);
+    assert_eq!(clone.peek(), Some(&4));
+    assert_eq!(heap.peek(), Some(&3));
+    assert_eq!(heap.into_vec().len(), 3);
+}
+
+#[test]
+fn test_allocator() {
+    let alloc = utils::AllocMock::new();
+    {
+        let mut heap = serial::DaryHeap::<_, 4, _, _>::with_comparator_in(
+            serial::binary_heap::MinComparator,
+            alloc.clone(),
+        );
+        for x in [5, 3, 8, 1, 9, 2] {
+            heap.try_push(x).unwrap();
+        }
+        assert_eq!(heap.allocator().alloc_cnt(), 1);
+        *heap.peek_mut().unwrap() = 7;
+        assert_eq!(heap.peek(), Some(&2));
+        assert_eq!(heap.drain_sorted().collect::<Vec<_>>(), [2, 3, 5, 7, 8, 9]);
+        assert!(heap.is_empty());
+    }
+    assert_eq!(alloc.alloc_cnt(), 0);
+}
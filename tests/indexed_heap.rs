@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+use utils::rand::RngCore;
+
+#[test]
+fn test_basic() {
+    let mut heap = serial::IndexedHeap::new();
+    assert!(heap.is_empty());
+    assert_eq!(heap.push("a", 3), None);
+    assert_eq!(heap.push("b", 5), None);
+    assert_eq!(heap.push("c", 1), None);
+    assert_eq!(heap.len(), 3);
+    assert_eq!(heap.peek(), Some((&"b", &5)));
+    assert_eq!(heap.pop(), Some(("b", 5)));
+    assert_eq!(heap.pop(), Some(("a", 3)));
+    assert_eq!(heap.pop(), Some(("c", 1)));
+    assert_eq!(heap.pop(), None);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_contains() {
+    let mut heap = serial::IndexedHeap::new();
+    heap.push(String::from("a"), 1);
+    heap.push(String::from("b"), 2);
+    assert!(heap.contains("a"));
+    assert!(heap.contains("b"));
+    assert!(!heap.contains("c"));
+    assert_eq!(heap.priority("a"), Some(&1));
+    assert_eq!(heap.priority("c"), None);
+
+    heap.pop();
+    assert!(!heap.contains("b"));
+    heap.clear();
+    assert!(!heap.contains("a"));
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_push_existing() {
+    let mut heap = serial::IndexedHeap::new();
+    heap.push(1, 10);
+    heap.push(2, 20);
+    assert_eq!(heap.push(1, 30), Some(10));
+    assert_eq!(heap.len(), 2);
+    assert_eq!(heap.pop(), Some((1, 30)));
+    assert_eq!(heap.pop(), Some((2, 20)));
+}
+
+#[test]
+fn test_change_priority() {
+    let mut heap: serial::IndexedHeap<u32, i32> = (0..10).map(|k| (k, k as i32)).collect();
+    assert_eq!(heap.change_priority(&3, 100), Some(3));
+    assert_eq!(heap.change_priority(&9, -1), Some(9));
+    assert_eq!(heap.change_priority(&42, 0), None);
+    assert_eq!(heap.peek(), Some((&3, &100)));
+
+    let keys: Vec<u32> = std::iter::from_fn(|| heap.pop()).map(|(k, _)| k).collect();
+    assert_eq!(keys, [3, 8, 7, 6, 5, 4, 2, 1, 0, 9]);
+}
+
+#[test]
+fn test_remove() {
+    let mut heap: serial::IndexedHeap<u32, u32> = (0..10).map(|k| (k, k)).collect();
+    assert_eq!(heap.remove(&9), Some(9));
+    assert_eq!(heap.remove(&0), Some(0));
+    assert_eq!(heap.remove(&5), Some(5));
+    assert_eq!(heap.remove(&5), None);
+    assert_eq!(heap.len(), 7);
+    assert!(!heap.contains(&5));
+
+    let keys: Vec<u32> = std::iter::from_fn(|| heap.pop()).map(|(k, _)| k).collect();
+    assert_eq!(keys, [8, 7, 6, 4, 3, 2, 1]);
+}
+
+#[test]
+fn test_dijkstra() {
+    // 0 -> 1 (4), 0 -> 2 (1), 2 -> 1 (2), 1 -> 3 (1), 2 -> 3 (5)
+    let edges: [&[(usize, u32)]; 4] = [&[(1, 4), (2, 1)], &[(3, 1)], &[(1, 2), (3, 5)], &[]];
+    let mut dist = [u32::MAX; 4];
+    let mut queue = serial::IndexedHeap::new_min();
+    queue.push(0, 0);
+    while let Some((node, d)) = queue.pop() {
+        dist[node] = d;
+        for &(next, w) in edges[node] {
+            if dist[next] != u32::MAX {
+                continue;
+            }
+            match queue.priority(&next) {
+                Some(&old) if old <= d + w => {}
+                Some(_) => drop(queue.change_priority(&next, d + w)),
+                None => drop(queue.push(next, d + w)),
+            }
+        }
+    }
+    assert_eq!(dist, [0, 3, 1, 4]);
+}
+
+#[test]
+fn test_random() {
+    let mut rng = utils::rand::thread_rng();
+    let mut heap = serial::IndexedHeap::new();
+    let mut expected = HashMap::new();
+    for _ in 0..5000 {
+        let key = rng.next_u32() % 200;
+        let priority = rng.next_u32() % 1000;
+        match rng.next_u32() % 4 {
+            0 => assert_eq!(heap.remove(&key), expected.remove(&key)),
+            1 => assert_eq!(heap.change_priority(&key, priority), {
+                expected
+                    .get_mut(&key)
+                    .map(|p| std::mem::replace(p, priority))
+            }),
+            _ => assert_eq!(heap.push(key, priority), expected.insert(key, priority)),
+        }
+        assert_eq!(heap.len(), expected.len());
+    }
+
+    let mut expected: Vec<(u32, u32)> = expected.into_iter().collect();
+    expected.sort_by_key(|&(k, p)| std::cmp::Reverse((p, k)));
+    let mut popped = Vec::new();
+    while let Some((key, priority)) = heap.pop() {
+        assert!(!heap.contains(&key));
+        popped.push((key, priority));
+    }
+    assert!(popped.windows(2).all(|w| w[0].1 >= w[1].1));
+    // Keys with equal priorities come out in any order.
+    popped.sort_by_key(|&(k, p)| std::cmp::Reverse((p, k)));
+    assert_eq!(popped, expected);
+}
+
+#[test]
+fn test_clone() {
+    let mut heap: serial::IndexedHeap<u32, u32> = (0..5).map(|k| (k, k)).collect();
+    let mut clone = heap.clone();
+    clone.change_priority(&0, 10);
+    assert_eq!(clone.pop(), Some((0, 10)));
+    assert_eq!(heap.pop(), Some((4, 4)));
+}
+
+#[test]
+fn test_pop_panic() {
+    let panic = std::cell::Cell::new(false);
+    let mut heap = serial::IndexedHeap::with_comparator(serial::binary_heap::FnComparator(
+        |a: &i32, b: &i32| {
+            assert!(!panic.get());
+            a.cmp(b)
+        },
+    ));
+    heap.extend([(1, 1), (2, 2), (3, 3)]);
+
+    panic.set(true);
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| heap.pop()));
+    assert!(result.is_err());
+    panic.set(false);
+
+    assert_eq!(heap.len(), 2);
+    assert!(!heap.contains(&3));
+    assert_eq!(heap.change_priority(&1, 5), Some(1));
+    assert_eq!(heap.pop(), Some((1, 5)));
+    assert_eq!(heap.pop(), Some((2, 2)));
+}
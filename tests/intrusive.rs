@@ -0,0 +1,142 @@
+use serial::intrusive::{Link, List};
+use std::pin::Pin;
+
+struct Entry {
+    value: i32,
+    all: Link,
+    even: Link,
+}
+
+impl Entry {
+    fn new(value: i32) -> Entry {
+        Entry {
+            value,
+            all: Link::new(),
+            even: Link::new(),
+        }
+    }
+}
+
+serial::intrusive_adapter!(AllAdapter = Entry { all });
+serial::intrusive_adapter!(EvenAdapter = Entry { even });
+
+fn values<'a, A: serial::intrusive::Adapter<Value = Entry>>(list: &List<'a, A>) -> Vec<i32> {
+    list.iter().map(|e| e.value).collect()
+}
+
+#[test]
+fn test_basic() {
+    let entries: Pin<Box<[Entry]>> = (0..6).map(Entry::new).collect::<Box<_>>().into();
+    let entries: Vec<Pin<&Entry>> = (0..6)
+        .map(|i| unsafe { entries.as_ref().map_unchecked(|e| &e[i]) })
+        .collect();
+
+    let mut all = List::<AllAdapter>::new();
+    let mut even = List::<EvenAdapter>::new();
+    assert!(all.is_empty());
+    for &entry in &entries {
+        all.push_back(entry);
+        if entry.value % 2 == 0 {
+            even.push_front(entry);
+        }
+    }
+    assert_eq!(all.len(), 6);
+    assert_eq!(values(&all), [0, 1, 2, 3, 4, 5]);
+    assert_eq!(values(&even), [4, 2, 0]);
+    assert_eq!(
+        all.iter().rev().map(|e| e.value).collect::<Vec<_>>(),
+        [5, 4, 3, 2, 1, 0]
+    );
+
+    unsafe { all.remove(entries[2]) };
+    assert!(!entries[2].all.is_linked());
+    assert!(entries[2].even.is_linked());
+    assert_eq!(values(&all), [0, 1, 3, 4, 5]);
+    assert_eq!(values(&even), [4, 2, 0]);
+
+    assert_eq!(all.pop_front().map(|e| e.value), Some(0));
+    assert_eq!(all.pop_back().map(|e| e.value), Some(5));
+    assert_eq!(even.front().map(|e| e.value), Some(4));
+    assert_eq!(even.back().map(|e| e.value), Some(0));
+    assert!(!entries[0].all.is_linked());
+
+    all.push_front(entries[0]);
+    assert_eq!(values(&all), [0, 1, 3, 4]);
+
+    drop(all);
+    assert!(entries.iter().all(|e| !e.all.is_linked()));
+    even.clear();
+    assert!(even.is_empty());
+    assert!(entries.iter().all(|e| !e.even.is_linked()));
+}
+
+#[test]
+fn test_append() {
+    let a = Box::pin(Entry::new(0));
+    let b = Box::pin(Entry::new(1));
+    let mut first = List::<AllAdapter>::new();
+    let mut second = List::<AllAdapter>::new();
+    first.append(&mut second);
+    assert!(first.is_empty());
+
+    first.push_back(a.as_ref());
+    second.push_back(b.as_ref());
+    first.append(&mut second);
+    assert!(second.is_empty());
+    assert_eq!(values(&first), [0, 1]);
+    assert_eq!(first.back().map(|e| e.value), Some(1));
+}
+
+// The link is preceded by several fields, so getting back from the link to
+// the element steps outside the link field itself.
+struct Padded {
+    tag: u8,
+    name: String,
+    weights: [u64; 3],
+    link: Link,
+}
+
+serial::intrusive_adapter!(PaddedAdapter = Padded { link });
+
+#[test]
+fn test_link_after_fields() {
+    let elements: Vec<_> = (0..4)
+        .map(|i| {
+            Box::pin(Padded {
+                tag: i,
+                name: i.to_string(),
+                weights: [i as u64; 3],
+                link: Link::new(),
+            })
+        })
+        .collect();
+
+    let mut list = List::<PaddedAdapter>::new();
+    for element in &elements {
+        list.push_front(element.as_ref());
+    }
+    unsafe { list.remove(elements[1].as_ref()) };
+    assert_eq!(
+        list.iter().map(|e| e.name.clone()).collect::<Vec<_>>(),
+        ["3", "2", "0"]
+    );
+    let back = list.pop_back().unwrap();
+    assert_eq!((back.tag, back.weights), (0, [0; 3]));
+    assert_eq!(list.front().map(|e| e.tag), Some(3));
+}
+
+#[test]
+#[should_panic]
+fn test_push_linked() {
+    let entry = Box::pin(Entry::new(0));
+    let mut first = List::<AllAdapter>::new();
+    let mut second = List::<AllAdapter>::new();
+    first.push_back(entry.as_ref());
+    second.push_back(entry.as_ref());
+}
+
+#[test]
+fn test_not_send() {
+    utils::assert_not_send!(List<'static, AllAdapter>);
+    utils::assert_not_sync!(List<'static, AllAdapter>);
+}
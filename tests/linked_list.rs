@@ -1,3 +1,5 @@
+use utils::rand::RngCore;
+
 #[test]
 fn test_basic() {
     let mut evil = serial::LinkedList::<i32>::new();
@@ -13,12 +15,125 @@ fn test_basic() {
     assert_eq!(evil.len(), 0);
 }
 
+#[test]
+fn test_from_iter_extend() {
+    let mut list: serial::LinkedList<i32> = (0..3).collect();
+    list.extend(3..5);
+    list.extend(&[5, 6]);
+    assert_eq!(list.len(), 7);
+    for i in 0..7 {
+        assert_eq!(list.pop_front(), Some(i));
+    }
+    assert_eq!(list.pop_front(), None);
+}
+
+#[test]
+fn test_clone() {
+    let list: serial::LinkedList<i32> = (0..5).collect();
+    let mut other = list.clone();
+    assert_eq!(list, other);
+    other.push_back(5);
+    assert_ne!(list, other);
+
+    let back = other.back().unwrap() as *const i32;
+    other.clone_from(&(0..6).rev().collect());
+    assert_eq!(other, (0..6).rev().collect());
+    assert_eq!(back, other.back().unwrap() as *const i32);
+
+    other.clone_from(&list);
+    assert_eq!(list, other);
+    other.clone_from(&(0..8).collect());
+    assert_eq!(other, (0..8).collect());
+}
+
+#[test]
+fn test_traits() {
+    let list: serial::LinkedList<i32> = (1..4).collect();
+    assert_eq!(format!("{list:?}"), "[1, 2, 3]");
+    assert!(list < [1, 2, 4].into_iter().collect());
+    assert!(list > [1, 2].into_iter().collect());
+    assert_eq!(list.cmp(&list.clone()), std::cmp::Ordering::Equal);
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(list.clone());
+    assert!(set.contains(&list));
+}
+
+#[test]
+fn test_try_push() {
+    let mut list = serial::LinkedList::<i32>::new();
+    assert!(list.try_push_back(2).is_ok());
+    assert!(list.try_push_front(1).is_ok());
+    assert!(list.try_push_back(3).is_ok());
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.pop_front(), Some(1));
+    assert_eq!(list.pop_front(), Some(2));
+    assert_eq!(list.pop_front(), Some(3));
+}
+
+#[test]
+fn test_try_push_alloc_error() {
+    let alloc = utils::AllocMock::new();
+    let mut list = serial::LinkedList::new_in(alloc.clone());
+    list.push_back(1);
+
+    alloc.fail_next_alloc();
+    assert!(matches!(
+        list.try_push_back(2).map_err(|e| e.kind()),
+        Err(serial::TryReserveErrorKind::AllocError { .. })
+    ));
+    alloc.fail_next_alloc();
+    assert!(matches!(
+        list.try_push_front(0).map_err(|e| e.kind()),
+        Err(serial::TryReserveErrorKind::AllocError { .. })
+    ));
+    assert_eq!(list.len(), 1);
+    assert_eq!(list.front(), Some(&1));
+    assert_eq!(list.back(), Some(&1));
+    assert_eq!(alloc.alloc_cnt(), 1);
+}
+
 #[test]
 #[allow(dead_code)]
 fn assert_covariance() {
     fn a<'a>(x: serial::LinkedList<&'static str>) -> serial::LinkedList<&'a str> {
         x
     }
+    fn b<'i, 'a>(
+        x: serial::linked_list::Iter<'i, &'static str>,
+    ) -> serial::linked_list::Iter<'i, &'a str> {
+        x
+    }
+    fn c<'a>(
+        x: serial::linked_list::IntoIter<&'static str>,
+    ) -> serial::linked_list::IntoIter<&'a str> {
+        x
+    }
+}
+
+#[test]
+fn assert_send_sync() {
+    utils::assert_send::<serial::LinkedList<i32>>();
+    utils::assert_sync::<serial::LinkedList<i32>>();
+
+    utils::assert_send::<serial::linked_list::Iter<'static, i32>>();
+    utils::assert_sync::<serial::linked_list::Iter<'static, i32>>();
+    utils::assert_send::<serial::linked_list::IterMut<'static, i32>>();
+    utils::assert_sync::<serial::linked_list::IterMut<'static, i32>>();
+    utils::assert_send::<serial::linked_list::IntoIter<i32>>();
+    utils::assert_sync::<serial::linked_list::IntoIter<i32>>();
+
+    utils::assert_send::<serial::linked_list::Cursor<'static, i32>>();
+    utils::assert_sync::<serial::linked_list::Cursor<'static, i32>>();
+    utils::assert_send::<serial::linked_list::CursorMut<'static, i32>>();
+    utils::assert_sync::<serial::linked_list::CursorMut<'static, i32>>();
+
+    utils::assert_not_send!(serial::LinkedList<std::rc::Rc<i32>>);
+    utils::assert_not_sync!(serial::LinkedList<std::cell::Cell<i32>>);
+    utils::assert_not_send!(serial::linked_list::Iter<'static, std::cell::Cell<i32>>);
+    utils::assert_not_sync!(serial::linked_list::IterMut<'static, std::cell::Cell<i32>>);
+    utils::assert_not_send!(serial::linked_list::Cursor<'static, std::cell::Cell<i32>>);
+    utils::assert_not_send!(serial::linked_list::CursorMut<'static, std::rc::Rc<i32>>);
 }
 
 #[test]
@@ -112,3 +227,605 @@ fn test_too_many_lists_basic() {
     assert_eq!(n.pop_front(), Some(0));
     assert_eq!(n.pop_front(), Some(1));
 }
+
+#[test]
+fn test_iter() {
+    let mut list: serial::LinkedList<i32> = (0..5).collect();
+    let mut iter = list.iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.clone().collect::<Vec<_>>(), [&1, &2, &3]);
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    for x in &mut list {
+        *x *= 10;
+    }
+    let mut iter = list.iter_mut();
+    *iter.next_back().unwrap() += 1;
+    assert_eq!(iter.len(), 4);
+    assert!(list.contains(&41));
+    assert!(!list.contains(&40));
+
+    let mut i = 0;
+    for x in &list {
+        assert_eq!(*x, i * 10 + (i == 4) as i32);
+        i += 1;
+    }
+    assert_eq!(i, 5);
+}
+
+#[test]
+fn test_into_iter() {
+    let list: serial::LinkedList<i32> = (0..5).collect();
+    let mut iter = list.into_iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+
+    let mock = utils::DropMock::new();
+    let list: serial::LinkedList<_> = (0..10).map(|_| mock.clone()).collect();
+    let mut iter = list.into_iter();
+    drop(iter.next());
+    assert_eq!(mock.drop_cnt(), 1);
+    drop(iter);
+    assert_eq!(mock.drop_cnt(), 10);
+}
+
+#[test]
+fn test_cursor_move_peek() {
+    let list: serial::LinkedList<i32> = (1..7).collect();
+    let mut cursor = list.cursor_front();
+    assert_eq!(cursor.current(), Some(&1));
+    assert_eq!(cursor.peek_next(), Some(&2));
+    assert_eq!(cursor.peek_prev(), None);
+    assert_eq!(cursor.index(), Some(0));
+    cursor.move_prev();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.peek_next(), Some(&1));
+    assert_eq!(cursor.peek_prev(), Some(&6));
+    assert_eq!(cursor.index(), None);
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&2));
+    assert_eq!(cursor.peek_next(), Some(&3));
+    assert_eq!(cursor.peek_prev(), Some(&1));
+    assert_eq!(cursor.index(), Some(1));
+
+    let mut cursor = list.cursor_back();
+    assert_eq!(cursor.current(), Some(&6));
+    assert_eq!(cursor.index(), Some(5));
+    cursor.move_next();
+    assert_eq!(cursor.current(), None);
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&6));
+
+    let empty = serial::LinkedList::<i32>::new();
+    let mut cursor = empty.cursor_front();
+    assert_eq!(cursor.current(), None);
+    cursor.move_next();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.index(), None);
+}
+
+#[test]
+fn test_cursor_mut_insert_remove() {
+    let mut list: serial::LinkedList<i32> = (1..7).collect();
+    let mut cursor = list.cursor_front_mut();
+    cursor.insert_before(7);
+    cursor.insert_after(8);
+    assert_eq!(cursor.index(), Some(1));
+    assert_eq!(cursor.current(), Some(&mut 1));
+    assert_eq!(cursor.peek_next(), Some(&mut 8));
+    assert_eq!(cursor.peek_prev(), Some(&mut 7));
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.current(), None);
+    cursor.insert_after(9);
+    cursor.insert_before(10);
+    assert_eq!(cursor.index(), None);
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&mut 9));
+    assert_eq!(cursor.remove_current(), Some(9));
+    assert_eq!(cursor.current(), Some(&mut 7));
+    *cursor.current().unwrap() = 70;
+    assert_eq!(cursor.as_cursor().current(), Some(&70));
+    assert_eq!(list.len(), 9);
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        [70, 1, 8, 2, 3, 4, 5, 6, 10]
+    );
+
+    let mut cursor = list.cursor_back_mut();
+    while cursor.remove_current().is_some() {
+        cursor.move_prev();
+    }
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_cursor_mut_splice_split() {
+    let mut list: serial::LinkedList<i32> = (1..7).collect();
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.splice_before((7..9).collect());
+    cursor.splice_after((10..12).collect());
+    assert_eq!(cursor.index(), Some(3));
+    assert_eq!(cursor.current(), Some(&mut 2));
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&mut 8));
+    cursor.splice_after(serial::LinkedList::new());
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        [1, 7, 8, 2, 10, 11, 3, 4, 5, 6]
+    );
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+    let front = cursor.split_before();
+    assert_eq!(cursor.index(), Some(0));
+    let back = cursor.split_after();
+    assert_eq!(cursor.index(), Some(0));
+    assert_eq!(front.iter().copied().collect::<Vec<_>>(), [1, 7]);
+    assert_eq!(front.len(), 2);
+    assert_eq!(list.iter().copied().collect::<Vec<_>>(), [8]);
+    assert_eq!(list.len(), 1);
+    assert_eq!(
+        back.iter().copied().collect::<Vec<_>>(),
+        [2, 10, 11, 3, 4, 5, 6]
+    );
+    assert_eq!(back.len(), 7);
+    assert_eq!(
+        back.iter().rev().copied().collect::<Vec<_>>(),
+        [6, 5, 4, 3, 11, 10, 2]
+    );
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_prev();
+    let all = cursor.split_after();
+    assert_eq!(all.len(), 1);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_append_prepend() {
+    let mut list: serial::LinkedList<i32> = (2..4).collect();
+    let mut other: serial::LinkedList<i32> = (4..6).collect();
+    list.append(&mut other);
+    assert!(other.is_empty());
+    assert_eq!(other.len(), 0);
+    let mut other: serial::LinkedList<i32> = (0..2).collect();
+    list.prepend(&mut other);
+    assert!(other.is_empty());
+    assert_eq!(list.len(), 6);
+    assert_eq!(list, (0..6).collect());
+    assert_eq!(
+        list.iter().rev().copied().collect::<Vec<_>>(),
+        [5, 4, 3, 2, 1, 0]
+    );
+
+    list.append(&mut serial::LinkedList::new());
+    assert_eq!(list.len(), 6);
+    other.append(&mut list);
+    assert!(list.is_empty());
+    assert_eq!(other, (0..6).collect());
+}
+
+#[test]
+fn test_split_off() {
+    for at in 0..=6 {
+        let mut list: serial::LinkedList<i32> = (0..6).collect();
+        let back = list.split_off(at);
+        assert_eq!(list.len(), at);
+        assert_eq!(back.len(), 6 - at);
+        assert_eq!(list, (0..at as i32).collect());
+        assert_eq!(back, (at as i32..6).collect());
+        assert_eq!(
+            back.iter().rev().copied().collect::<Vec<_>>(),
+            (at as i32..6).rev().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_split_off_out_of_bounds() {
+    let mut list: serial::LinkedList<i32> = (0..6).collect();
+    list.split_off(7);
+}
+
+#[test]
+fn test_clear() {
+    let mock = utils::DropMock::new();
+    let mut list: serial::LinkedList<_> = (0..10).map(|_| mock.clone()).collect();
+    list.clear();
+    assert!(list.is_empty());
+    assert_eq!(list.len(), 0);
+    assert_eq!(mock.drop_cnt(), 10);
+    list.push_back(mock.clone());
+    assert_eq!(list.len(), 1);
+}
+
+#[test]
+fn test_retain() {
+    let mut list: serial::LinkedList<i32> = (0..10).collect();
+    let front = list.front().unwrap() as *const i32;
+    list.retain(|x| x % 2 == 0);
+    assert_eq!(list, [0, 2, 4, 6, 8].into_iter().collect());
+    assert_eq!(list.len(), 5);
+    assert_eq!(front, list.front().unwrap() as *const i32);
+
+    list.retain_mut(|x| {
+        *x += 1;
+        *x > 4
+    });
+    assert_eq!(list, [5, 7, 9].into_iter().collect());
+    assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [9, 7, 5]);
+
+    list.retain(|_| false);
+    assert!(list.is_empty());
+    assert_eq!(list.back(), None);
+}
+
+#[test]
+fn test_extract_if() {
+    let mut list: serial::LinkedList<i32> = (0..10).collect();
+    {
+        let mut extracted = list.extract_if(|x| *x % 3 == 0);
+        assert_eq!(extracted.next(), Some(0));
+        assert_eq!(extracted.next(), Some(3));
+    }
+    assert_eq!(list, [1, 2, 4, 5, 6, 7, 8, 9].into_iter().collect());
+    assert_eq!(list.len(), 8);
+
+    let extracted: Vec<_> = list.extract_if(|x| *x > 5).collect();
+    assert_eq!(extracted, [6, 7, 8, 9]);
+    assert_eq!(list, [1, 2, 4, 5].into_iter().collect());
+    assert_eq!(list.back(), Some(&5));
+}
+
+#[test]
+fn test_extract_if_panic() {
+    let mock = utils::DropMock::new();
+    let mut list: serial::LinkedList<_> = (0..10).map(|i| (i, mock.clone())).collect();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        list.retain(|(i, _)| {
+            if *i == 5 {
+                panic!("predicate panic");
+            }
+            i % 2 == 0
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(mock.drop_cnt(), 2);
+    assert_eq!(list.len(), 8);
+    assert_eq!(
+        list.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        [0, 2, 4, 5, 6, 7, 8, 9]
+    );
+    assert_eq!(
+        list.iter().rev().map(|(i, _)| *i).collect::<Vec<_>>(),
+        [9, 8, 7, 6, 5, 4, 2, 0]
+    );
+    drop(list);
+    assert_eq!(mock.drop_cnt(), 10);
+}
+
+#[test]
+fn test_sort() {
+    let mut rng = utils::rand::thread_rng();
+    for len in [0, 1, 2, 3, 7, 64, 100, 1000] {
+        let data: Vec<u32> = (0..len).map(|_| rng.next_u32() % 100).collect();
+        let mut list: serial::LinkedList<u32> = data.iter().copied().collect();
+        let addresses: std::collections::HashSet<_> =
+            list.iter().map(|x| x as *const u32).collect();
+        list.sort();
+
+        let mut expected = data.clone();
+        expected.sort();
+        assert_eq!(list.len(), len);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        expected.reverse();
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            addresses,
+            list.iter()
+                .map(|x| x as *const u32)
+                .collect::<std::collections::HashSet<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_sort_stable() {
+    let mut list: serial::LinkedList<(u32, usize)> =
+        (0..100).map(|i| ((i * 7 % 10) as u32, i)).collect();
+    list.sort_by_key(|(key, _)| *key);
+    let sorted: Vec<_> = list.iter().copied().collect();
+    for pair in sorted.windows(2) {
+        assert!(pair[0].0 < pair[1].0 || (pair[0].0 == pair[1].0 && pair[0].1 < pair[1].1));
+    }
+
+    list.sort_by(|a, b| b.0.cmp(&a.0));
+    assert_eq!(list.front(), Some(&(9, 7)));
+    assert_eq!(list.back(), Some(&(0, 90)));
+}
+
+#[test]
+fn test_sort_panic() {
+    let mock = utils::DropMock::new();
+    let mut list: serial::LinkedList<_> = (0..100).map(|i| (i, mock.clone())).collect();
+    let mut comparisons = 0;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        list.sort_by(|a, b| {
+            comparisons += 1;
+            if comparisons == 150 {
+                panic!("compare panic");
+            }
+            b.0.cmp(&a.0)
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(list.len(), 100);
+    let mut elements: Vec<_> = list.iter().map(|(i, _)| *i).collect();
+    assert_eq!(elements.len(), 100);
+    elements.reverse();
+    assert_eq!(
+        list.iter().rev().map(|(i, _)| *i).collect::<Vec<_>>(),
+        elements
+    );
+    elements.sort();
+    assert_eq!(elements, (0..100).collect::<Vec<_>>());
+    drop(list);
+    assert_eq!(mock.drop_cnt(), 100);
+}
+
+#[test]
+fn test_merge() {
+    let mut list: serial::LinkedList<(u32, char)> = [(1, 'a'), (3, 'a'), (5, 'a'), (7, 'a')]
+        .into_iter()
+        .collect();
+    let mut other: serial::LinkedList<(u32, char)> =
+        [(0, 'b'), (3, 'b'), (8, 'b')].into_iter().collect();
+    list.merge(&mut other);
+    assert!(other.is_empty());
+    assert_eq!(other.len(), 0);
+    assert_eq!(list.len(), 7);
+    assert_eq!(
+        list.iter().copied().collect::<Vec<_>>(),
+        [
+            (0, 'b'),
+            (1, 'a'),
+            (3, 'a'),
+            (3, 'b'),
+            (5, 'a'),
+            (7, 'a'),
+            (8, 'b')
+        ]
+    );
+    assert_eq!(list.back(), Some(&(8, 'b')));
+
+    let mut empty = serial::LinkedList::new();
+    empty.merge(&mut list);
+    assert_eq!(empty.len(), 7);
+    assert_eq!(empty.front(), Some(&(0, 'b')));
+}
+
+#[test]
+fn test_reverse() {
+    for len in 0..5 {
+        let mut list: serial::LinkedList<i32> = (0..len).collect();
+        let front = list.front().map(|x| x as *const i32);
+        list.reverse();
+        assert_eq!(list.len(), len as usize);
+        assert_eq!(list, (0..len).rev().collect());
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            (0..len).collect::<Vec<_>>()
+        );
+        assert_eq!(front, list.back().map(|x| x as *const i32));
+    }
+}
+
+#[test]
+fn test_rotate() {
+    for n in 0..=5 {
+        let mut list: serial::LinkedList<i32> = (0..5).collect();
+        let addresses: Vec<_> = list.iter().map(|x| x as *const i32).collect();
+        list.rotate_left(n);
+        let expected: Vec<_> = (0..5).map(|i| ((i + n) % 5) as i32).collect();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<_>>(),
+            expected.iter().rev().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            list.cursor_front().current().map(|x| x as *const i32),
+            Some(addresses[n % 5])
+        );
+
+        list.rotate_right(n);
+        assert_eq!(list, (0..5).collect());
+        assert_eq!(list.len(), 5);
+    }
+
+    let mut empty = serial::LinkedList::<i32>::new();
+    empty.rotate_left(0);
+    empty.rotate_right(0);
+    assert!(empty.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_out_of_bounds() {
+    let mut list: serial::LinkedList<i32> = (0..5).collect();
+    list.rotate_left(6);
+}
+
+#[test]
+fn test_swap_ends() {
+    for len in 0..6 {
+        let mut list: serial::LinkedList<i32> = (0..len).collect();
+        list.swap_ends();
+        let mut expected: Vec<_> = (0..len).collect();
+        if len > 1 {
+            expected.swap(0, len as usize - 1);
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        expected.reverse();
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), expected);
+    }
+
+    let mut list: serial::LinkedList<i32> = (0..4).collect();
+    let mut cursor = list.cursor_front_mut();
+    let first = cursor.current().unwrap() as *mut i32;
+    list.swap_ends();
+    assert_eq!(list.back_mut().map(|x| x as *mut i32), Some(first));
+}
+
+#[test]
+fn test_node_pool() {
+    let mut list = serial::LinkedList::with_node_pool(4);
+    assert_eq!(list.pool_len(), 4);
+
+    list.push_back(1);
+    let node = list.back().unwrap() as *const i32;
+    assert_eq!(list.pool_len(), 3);
+    assert_eq!(list.pop_back(), Some(1));
+    assert_eq!(list.pool_len(), 4);
+    list.push_front(2);
+    assert_eq!(list.front().unwrap() as *const i32, node);
+
+    list.extend(0..8);
+    assert_eq!(list.len(), 9);
+    assert_eq!(list.pool_len(), 0);
+    list.clear();
+    assert_eq!(list.pool_len(), 4);
+    list.shrink_pool();
+    assert_eq!(list.pool_len(), 0);
+
+    list.try_push_back(3).unwrap();
+    assert_eq!(list.pool_len(), 0);
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.pool_len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_node_pool_zero() {
+    serial::LinkedList::<i32>::with_node_pool(0);
+}
+
+#[test]
+fn test_node_pool_drop() {
+    let mock = utils::DropMock::new();
+    {
+        let mut list = serial::LinkedList::with_node_pool(2);
+        list.extend((0..5).map(|_| mock.clone()));
+        drop(list.pop_front());
+        list.retain(|_| false);
+        assert_eq!(mock.drop_cnt(), 5);
+        list.push_back(mock.clone());
+    }
+    assert_eq!(mock.drop_cnt(), 6);
+}
+
+#[test]
+fn test_node_pool_transfer() {
+    let mut pooled = serial::LinkedList::with_node_pool(2);
+    let mut plain: serial::LinkedList<i32> = (0..4).collect();
+    pooled.append(&mut plain);
+    assert_eq!(pooled.pool_len(), 2);
+
+    // Nodes allocated by the other list end up in the pool, up to its capacity.
+    pooled.clear();
+    assert_eq!(pooled.pool_len(), 2);
+
+    pooled.extend(0..6);
+    let mut tail = pooled.split_off(3);
+    assert_eq!(tail.pool_len(), 0);
+    tail.clear();
+    assert_eq!(tail.pool_len(), 2);
+
+    let mut clone = pooled.clone();
+    assert_eq!(clone.pool_len(), 0);
+    clone.pop_front();
+    assert_eq!(clone.pool_len(), 1);
+
+    plain.prepend(&mut pooled);
+    assert_eq!(pooled.pool_len(), 0);
+    assert_eq!(plain, (0..3).collect());
+    plain.clear();
+    assert_eq!(plain.pool_len(), 0);
+}
+
+#[test]
+fn test_allocator() {
+    let alloc = utils::AllocMock::new();
+    {
+        let mut list = serial::LinkedList::new_in(alloc.clone());
+        assert_eq!(alloc.alloc_cnt(), 0);
+        list.extend(0..10);
+        assert_eq!(list.allocator().alloc_cnt(), 10);
+        assert_eq!(list.pop_back(), Some(9));
+        assert_eq!(alloc.alloc_cnt(), 9);
+
+        let mut tail = list.split_off(4);
+        assert_eq!(tail.allocator().alloc_cnt(), 9);
+        tail.push_front(-1);
+        list.try_push_back(-2).unwrap();
+        assert_eq!(alloc.alloc_cnt(), 11);
+
+        let mut cursor = tail.cursor_front_mut();
+        cursor.insert_before(-3);
+        assert_eq!(alloc.alloc_cnt(), 12);
+        assert_eq!(cursor.remove_current(), Some(-1));
+        assert_eq!(alloc.alloc_cnt(), 11);
+
+        let clone = list.clone();
+        assert_eq!(alloc.alloc_cnt(), 16);
+        drop(clone);
+        list.append(&mut tail);
+        assert_eq!(list.len(), 11);
+    }
+    assert_eq!(alloc.alloc_cnt(), 0);
+
+    {
+        let mut list = serial::LinkedList::with_node_pool_in(4, alloc.clone());
+        assert_eq!(alloc.alloc_cnt(), 4);
+        list.extend(0..6);
+        assert_eq!(alloc.alloc_cnt(), 6);
+        list.clear();
+        assert_eq!(alloc.alloc_cnt(), 4);
+        list.push_back(0);
+    }
+    assert_eq!(alloc.alloc_cnt(), 0);
+}
+
+#[test]
+fn test_node_pool_alloc_error() {
+    let alloc = utils::AllocMock::new();
+    let mut list = serial::LinkedList::with_node_pool_in(2, alloc.clone());
+    list.extend(0..2);
+    assert_eq!(list.pool_len(), 0);
+
+    alloc.fail_next_alloc();
+    assert!(matches!(
+        list.try_push_back(2).map_err(|e| e.kind()),
+        Err(serial::TryReserveErrorKind::AllocError { .. })
+    ));
+    assert_eq!(list.len(), 2);
+    assert_eq!(list.back(), Some(&1));
+    assert_eq!(alloc.alloc_cnt(), 2);
+
+    list.try_push_back(2).unwrap();
+    assert_eq!(list.pool_len(), 0);
+    assert_eq!(alloc.alloc_cnt(), 3);
+}
@@ -0,0 +1,138 @@
+use std::collections::VecDeque;
+use utils::rand::RngCore;
+use utils::OrderedDropMock;
+
+#[test]
+fn test_basic() {
+    let mut heap = serial::MinMaxHeap::new();
+    assert!(heap.is_empty());
+    assert_eq!(heap.peek_min(), None);
+    assert_eq!(heap.peek_max(), None);
+    for x in [3, 4, 1, 2, 5] {
+        heap.push(x);
+    }
+    assert_eq!(heap.len(), 5);
+    assert_eq!(heap.peek_min(), Some(&1));
+    assert_eq!(heap.peek_max(), Some(&5));
+    assert_eq!(heap.pop_max(), Some(5));
+    assert_eq!(heap.pop_min(), Some(1));
+    assert_eq!(heap.pop_max(), Some(4));
+    assert_eq!(heap.pop_min(), Some(2));
+    assert_eq!(heap.pop_max(), Some(3));
+    assert_eq!(heap.pop_min(), None);
+    assert_eq!(heap.pop_max(), None);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_from_iter_extend() {
+    let mut heap: serial::MinMaxHeap<i32> = [3, 1, 2].into_iter().collect();
+    heap.extend([5, 4]);
+    heap.extend(&[0, 6]);
+    assert_eq!(heap.len(), 7);
+    for i in 0..7 {
+        assert_eq!(heap.pop_min(), Some(i));
+    }
+
+    let mut heap = serial::MinMaxHeap::from([3, 1, 4, 1, 5, 9, 2, 6]);
+    let popped: Vec<i32> = std::iter::from_fn(|| heap.pop_max()).collect();
+    assert_eq!(popped, [9, 6, 5, 4, 3, 2, 1, 1]);
+}
+
+#[test]
+fn test_push_pop_max() {
+    let mut heap = serial::MinMaxHeap::new();
+    assert_eq!(heap.push_pop_max(1), 1);
+    assert!(heap.is_empty());
+
+    heap.push(5);
+    assert_eq!(heap.push_pop_max(7), 7);
+    assert_eq!(heap.push_pop_max(3), 5);
+    assert_eq!(heap.peek_min(), Some(&3));
+
+    heap.extend([8, 2, 6]);
+    assert_eq!(heap.push_pop_max(0), 8);
+    assert_eq!(heap.peek_min(), Some(&0));
+    assert_eq!(heap.peek_max(), Some(&6));
+    let popped: Vec<i32> = std::iter::from_fn(|| heap.pop_min()).collect();
+    assert_eq!(popped, [0, 2, 3, 6]);
+}
+
+#[test]
+fn test_top_k() {
+    let mut rng = utils::rand::thread_rng();
+    let values: Vec<u32> = (0..1000).map(|_| rng.next_u32() % 500).collect();
+
+    let mut heap = serial::MinMaxHeap::with_capacity(10);
+    for &x in &values {
+        if heap.len() < 10 {
+            heap.push(x);
+        } else {
+            heap.push_pop_max(x);
+        }
+    }
+    assert_eq!(heap.capacity(), 10);
+
+    let mut expected = values.clone();
+    expected.sort();
+    expected.truncate(10);
+    let popped: Vec<u32> = std::iter::from_fn(|| heap.pop_min()).collect();
+    assert_eq!(popped, expected);
+}
+
+#[test]
+fn test_random() {
+    let mut rng = utils::rand::thread_rng();
+    for len in [0, 1, 2, 3, 4, 10, 100, 1000] {
+        let vec: serial::Vec<u32> = (0..len).map(|_| rng.next_u32() % 100).collect();
+        let mut expected: Vec<u32> = vec.iter().copied().collect();
+        expected.sort();
+        let mut expected = VecDeque::from(expected);
+
+        let mut heap = serial::MinMaxHeap::from(vec);
+        for _ in 0..len * 2 {
+            match rng.next_u32() % 4 {
+                0 => assert_eq!(heap.pop_min(), expected.pop_front()),
+                1 => assert_eq!(heap.pop_max(), expected.pop_back()),
+                2 => {
+                    let x = rng.next_u32() % 100;
+                    heap.push(x);
+                    let at = expected.partition_point(|&y| y < x);
+                    expected.insert(at, x);
+                }
+                _ => {
+                    let x = rng.next_u32() % 100;
+                    let at = expected.partition_point(|&y| y < x);
+                    expected.insert(at, x);
+                    assert_eq!(Some(heap.push_pop_max(x)), expected.pop_back());
+                }
+            }
+            assert_eq!(heap.len(), expected.len());
+            assert_eq!(heap.peek_min(), expected.front());
+            assert_eq!(heap.peek_max(), expected.back());
+        }
+    }
+}
+
+#[test]
+fn test_drop() {
+    let mock = utils::DropMock::new();
+    let mut heap: serial::MinMaxHeap<_> =
+        (0..10).map(|i| OrderedDropMock(i, mock.clone())).collect();
+    assert_eq!(heap.pop_min().map(|x| x.0), Some(0));
+    assert_eq!(heap.pop_max().map(|x| x.0), Some(9));
+    assert_eq!(mock.drop_cnt(), 2);
+    drop(heap);
+    assert_eq!(mock.drop_cnt(), 10);
+}
+
+#[test]
+fn test_into_vec() {
+    let heap = serial::MinMaxHeap::from([2, 3, 1]);
+    let mut vec: Vec<i32> = heap.iter().copied().collect();
+    vec.sort();
+    assert_eq!(vec, [1, 2, 3]);
+    assert_eq!(heap.as_slice().len(), 3);
+    assert_eq!(serial::Vec::from(heap.clone()).len(), 3);
+    assert_eq!(heap.into_vec().len(), 3);
+}
@@ -0,0 +1,53 @@
+use std::alloc::{AllocError, Allocator, Global, Layout};
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::rc::Rc;
+
+/// AllocMock forwards to `Global` and counts live allocations.
+pub struct AllocMock {
+    counter: Rc<Cell<usize>>,
+    fail_next: Rc<Cell<bool>>,
+}
+
+impl AllocMock {
+    pub fn new() -> AllocMock {
+        AllocMock {
+            counter: Rc::new(Cell::new(0)),
+            fail_next: Rc::new(Cell::new(false)),
+        }
+    }
+
+    pub fn alloc_cnt(&self) -> usize {
+        self.counter.get()
+    }
+
+    /// Makes the next allocation (or reallocation) return `AllocError`.
+    pub fn fail_next_alloc(&self) {
+        self.fail_next.set(true);
+    }
+}
+
+impl Clone for AllocMock {
+    fn clone(&self) -> Self {
+        AllocMock {
+            counter: self.counter.clone(),
+            fail_next: self.fail_next.clone(),
+        }
+    }
+}
+
+unsafe impl Allocator for AllocMock {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if self.fail_next.replace(false) {
+            return Err(AllocError);
+        }
+        let ptr = Global.allocate(layout)?;
+        self.counter.set(self.counter.get() + 1);
+        Ok(ptr)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.counter.set(self.counter.get() - 1);
+        unsafe { Global.deallocate(ptr, layout) }
+    }
+}
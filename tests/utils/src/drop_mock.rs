@@ -1,4 +1,5 @@
 use std::cell::Cell;
+use std::cmp::Ordering;
 use std::rc::Rc;
 
 pub struct DropMock {
@@ -30,3 +31,26 @@ impl Drop for DropMock {
         self.counter.set(self.counter.get() + 1);
     }
 }
+
+/// OrderedDropMock counts drops and ordered like u32.
+pub struct OrderedDropMock(pub u32, pub DropMock);
+
+impl PartialEq for OrderedDropMock {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for OrderedDropMock {}
+
+impl PartialOrd for OrderedDropMock {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedDropMock {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
@@ -1,7 +1,15 @@
+#![feature(allocator_api)]
+
 #[cfg(test)]
 extern crate utils;
 
 mod drop_mock;
-pub use drop_mock::DropMock;
+pub use drop_mock::{DropMock, OrderedDropMock};
+
+mod alloc_mock;
+pub use alloc_mock::AllocMock;
+
+pub mod marker;
+pub use marker::{assert_send, assert_sync};
 
 pub use rand;
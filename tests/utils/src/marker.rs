@@ -0,0 +1,35 @@
+//! Compile-time assertions about auto traits.
+
+pub fn assert_send<T: Send>() {}
+
+pub fn assert_sync<T: Sync>() {}
+
+#[doc(hidden)]
+pub trait AmbiguousIfSend<A> {
+    fn some_item() {}
+}
+impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+impl<T: ?Sized + Send> AmbiguousIfSend<u8> for T {}
+
+#[doc(hidden)]
+pub trait AmbiguousIfSync<A> {
+    fn some_item() {}
+}
+impl<T: ?Sized> AmbiguousIfSync<()> for T {}
+impl<T: ?Sized + Sync> AmbiguousIfSync<u8> for T {}
+
+/// Fails to compile if the type is `Send`.
+#[macro_export]
+macro_rules! assert_not_send {
+    ($t:ty) => {
+        let _ = <$t as $crate::marker::AmbiguousIfSend<_>>::some_item;
+    };
+}
+
+/// Fails to compile if the type is `Sync`.
+#[macro_export]
+macro_rules! assert_not_sync {
+    ($t:ty) => {
+        let _ = <$t as $crate::marker::AmbiguousIfSync<_>>::some_item;
+    };
+}
@@ -9,9 +9,9 @@ fn test_basic() {
     assert_eq!(vec[0], 1);
     assert_eq!(vec[1], 2);
     assert_eq!(vec[2], 3);
-    vec.remove(0);
-    vec.remove(1);
-    vec.remove(0);
+    assert_eq!(vec.remove(0), 1);
+    assert_eq!(vec.remove(1), 3);
+    assert_eq!(vec.remove(0), 2);
     assert_eq!(vec.len(), 0);
 }
 
@@ -21,6 +21,26 @@ fn assert_covariance() {
     fn a<'a>(x: serial::Vec<&'static str>) -> serial::Vec<&'a str> {
         x
     }
+    fn b<'a>(x: serial::vec::IntoIter<&'static str>) -> serial::vec::IntoIter<&'a str> {
+        x
+    }
+}
+
+#[test]
+fn assert_send_sync() {
+    utils::assert_send::<serial::Vec<i32>>();
+    utils::assert_sync::<serial::Vec<i32>>();
+    utils::assert_send::<serial::vec::IntoIter<i32>>();
+    utils::assert_sync::<serial::vec::IntoIter<i32>>();
+    utils::assert_send::<serial::vec::Drain<'static, i32>>();
+    utils::assert_sync::<serial::vec::Drain<'static, i32>>();
+
+    utils::assert_not_send!(serial::Vec<std::rc::Rc<i32>>);
+    utils::assert_not_sync!(serial::Vec<std::cell::Cell<i32>>);
+    utils::assert_not_send!(serial::vec::IntoIter<std::rc::Rc<i32>>);
+    utils::assert_not_sync!(serial::vec::IntoIter<std::cell::Cell<i32>>);
+    utils::assert_not_send!(serial::vec::Drain<'static, std::rc::Rc<i32>>);
+    utils::assert_not_sync!(serial::vec::Drain<'static, std::cell::Cell<i32>>);
 }
 
 #[test]
@@ -49,8 +69,10 @@ fn test_append() {
         other.push(i);
     }
     let old_len = vec.len();
+    let other_len = other.len();
     vec.append(&mut other);
-    assert_eq!(vec.len(), old_len + other.len());
+    assert_eq!(vec.len(), old_len + other_len);
+    assert_eq!(other.len(), 0);
     for (i, v) in vec.iter().enumerate() {
         assert_eq!(i as i32, *v);
     }
@@ -68,3 +90,362 @@ fn test_insert() {
         assert_eq!(i as i32, *v);
     }
 }
+
+#[test]
+fn test_allocator() {
+    let alloc = utils::AllocMock::new();
+    {
+        let mut vec = serial::Vec::<i32, _>::new_in(alloc.clone());
+        assert_eq!(alloc.alloc_cnt(), 0);
+        for i in 0..100 {
+            vec.push(i);
+        }
+        assert_eq!(alloc.alloc_cnt(), 1);
+        assert_eq!(vec.allocator().alloc_cnt(), 1);
+        for (i, v) in vec.iter().enumerate() {
+            assert_eq!(i as i32, *v);
+        }
+    }
+    assert_eq!(alloc.alloc_cnt(), 0);
+}
+
+#[test]
+fn test_from_raw_parts_in() {
+    let alloc = utils::AllocMock::new();
+    {
+        let mut vec = serial::Vec::<i32, _>::with_capacity_in(5, alloc.clone());
+        for i in 0..5 {
+            vec.push(i);
+        }
+        let mut vec = std::mem::ManuallyDrop::new(vec);
+        let (ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+        let vec = unsafe { serial::Vec::from_raw_parts_in(ptr, len, cap, alloc.clone()) };
+        assert_eq!(alloc.alloc_cnt(), 1);
+        for (i, v) in vec.iter().enumerate() {
+            assert_eq!(i as i32, *v);
+        }
+    }
+    assert_eq!(alloc.alloc_cnt(), 0);
+}
+
+#[test]
+fn test_into_iter() {
+    let mut vec = serial::Vec::<i32>::new();
+    for i in 0..5 {
+        vec.push(i);
+    }
+    let mut iter = vec.into_iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.as_slice(), &[1, 2, 3]);
+    iter.as_mut_slice()[0] = 10;
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(10));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.len(), 0);
+}
+
+#[test]
+fn test_into_iter_dropck() {
+    let mock = utils::DropMock::new();
+    let mut vec = serial::Vec::<utils::DropMock>::new();
+    for _ in 0..10 {
+        vec.push(mock.clone());
+    }
+    let mut iter = vec.into_iter();
+    drop(iter.next());
+    drop(iter.next_back());
+    assert_eq!(mock.drop_cnt(), 2);
+    drop(iter);
+    assert_eq!(mock.drop_cnt(), 10);
+}
+
+#[test]
+fn test_into_iter_zst() {
+    let mut vec = serial::Vec::<()>::new();
+    for _ in 0..10 {
+        vec.push(());
+    }
+    assert_eq!(vec.into_iter().count(), 10);
+}
+
+#[test]
+fn test_iter_by_ref() {
+    let mut vec = serial::Vec::<i32>::new();
+    for i in 0..5 {
+        vec.push(i);
+    }
+    for v in &mut vec {
+        *v *= 2;
+    }
+    for (i, v) in (&vec).into_iter().enumerate() {
+        assert_eq!(2 * i as i32, *v);
+    }
+}
+
+#[test]
+fn test_drain() {
+    let mut vec = serial::Vec::<i32>::new();
+    for i in 0..10 {
+        vec.push(i);
+    }
+    let mut drain = vec.drain(2..6);
+    assert_eq!(drain.len(), 4);
+    assert_eq!(drain.as_slice(), &[2, 3, 4, 5]);
+    assert_eq!(drain.next(), Some(2));
+    assert_eq!(drain.next_back(), Some(5));
+    drop(drain);
+    assert_eq!(&*vec, &[0, 1, 6, 7, 8, 9]);
+
+    let front: std::vec::Vec<i32> = vec.drain(..3).collect();
+    assert_eq!(front, [0, 1, 6]);
+    assert_eq!(&*vec, &[7, 8, 9]);
+
+    vec.drain(..);
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn test_drain_leak() {
+    let mut vec = serial::Vec::<i32>::new();
+    for i in 0..10 {
+        vec.push(i);
+    }
+    let mut drain = vec.drain(3..5);
+    drain.next();
+    std::mem::forget(drain);
+    assert_eq!(&*vec, &[0, 1, 2]);
+}
+
+#[test]
+fn test_drain_dropck() {
+    let mock = utils::DropMock::new();
+    {
+        let mut vec = serial::Vec::<utils::DropMock>::new();
+        for _ in 0..10 {
+            vec.push(mock.clone());
+        }
+        let mut drain = vec.drain(4..8);
+        drop(drain.next());
+        drop(drain);
+        assert_eq!(mock.drop_cnt(), 4);
+        assert_eq!(vec.len(), 6);
+    }
+    assert_eq!(mock.drop_cnt(), 10);
+}
+
+#[test]
+fn test_drain_zst() {
+    let mut vec = serial::Vec::<()>::new();
+    for _ in 0..10 {
+        vec.push(());
+    }
+    assert_eq!(vec.drain(2..5).count(), 3);
+    assert_eq!(vec.len(), 7);
+}
+
+#[test]
+#[should_panic]
+fn test_drain_out_of_bounds() {
+    let mut vec = serial::Vec::<i32>::new();
+    vec.push(1);
+    vec.drain(0..2);
+}
+
+#[test]
+fn test_remove() {
+    let mut vec = serial::Vec::<i32>::new();
+    for i in 0..6 {
+        vec.push(i);
+    }
+    assert_eq!(vec.remove(1), 1);
+    assert_eq!(&*vec, &[0, 2, 3, 4, 5]);
+    assert_eq!(vec.remove(4), 5);
+    assert_eq!(&*vec, &[0, 2, 3, 4]);
+    assert_eq!(vec.remove(0), 0);
+    assert_eq!(&*vec, &[2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn test_remove_out_of_bounds() {
+    let mut vec = serial::Vec::<i32>::new();
+    vec.push(1);
+    vec.remove(1);
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut vec = serial::Vec::<i32>::new();
+    for i in 0..5 {
+        vec.push(i);
+    }
+    assert_eq!(vec.swap_remove(1), 1);
+    assert_eq!(&*vec, &[0, 4, 2, 3]);
+    assert_eq!(vec.swap_remove(3), 3);
+    assert_eq!(&*vec, &[0, 4, 2]);
+}
+
+#[test]
+fn test_try_reserve() {
+    let mut vec = serial::Vec::<i32>::new();
+    assert!(vec.try_reserve(10).is_ok());
+    assert!(vec.capacity() >= 10);
+    assert!(vec.try_reserve_exact(20).is_ok());
+    assert_eq!(vec.capacity(), 20);
+    assert_eq!(
+        vec.try_reserve(usize::MAX).map_err(|e| e.kind()),
+        Err(serial::TryReserveErrorKind::CapacityOverflow)
+    );
+    assert_eq!(
+        vec.try_reserve_exact(usize::MAX / 2).map_err(|e| e.kind()),
+        Err(serial::TryReserveErrorKind::CapacityOverflow)
+    );
+    assert_eq!(vec.capacity(), 20);
+
+    assert!(serial::Vec::<i32>::try_with_capacity(usize::MAX).is_err());
+    assert_eq!(
+        serial::Vec::<i32>::try_with_capacity(5).unwrap().capacity(),
+        5
+    );
+}
+
+#[test]
+fn test_try_push() {
+    let mut vec = serial::Vec::<i32>::new();
+    for i in 0..5 {
+        assert!(vec.try_push(i).is_ok());
+    }
+    assert!(vec.try_insert(0, -1).is_ok());
+    assert!(vec.try_insert(6, 5).is_ok());
+    assert_eq!(&*vec, &[-1, 0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_try_alloc_error() {
+    let alloc = utils::AllocMock::new();
+    let mut vec = serial::Vec::new_in(alloc.clone());
+    vec.push(1);
+    let capacity = vec.capacity();
+
+    alloc.fail_next_alloc();
+    assert!(matches!(
+        vec.try_reserve(capacity).map_err(|e| e.kind()),
+        Err(serial::TryReserveErrorKind::AllocError { .. })
+    ));
+    assert_eq!(vec.capacity(), capacity);
+    assert_eq!(&*vec, &[1]);
+
+    for i in 2..=capacity as i32 {
+        vec.push(i);
+    }
+    alloc.fail_next_alloc();
+    assert!(matches!(
+        vec.try_push(0).map_err(|e| e.kind()),
+        Err(serial::TryReserveErrorKind::AllocError { .. })
+    ));
+    assert_eq!(vec.len(), capacity);
+    assert_eq!(vec.capacity(), capacity);
+    assert_eq!(alloc.alloc_cnt(), 1);
+}
+
+#[test]
+fn test_shrink() {
+    let alloc = utils::AllocMock::new();
+    let mut vec = serial::Vec::<i32, _>::with_capacity_in(100, alloc.clone());
+    for i in 0..10 {
+        vec.push(i);
+    }
+    vec.shrink_to(50);
+    assert_eq!(vec.capacity(), 50);
+    vec.shrink_to(0);
+    assert_eq!(vec.capacity(), 10);
+    vec.reserve_exact(10);
+    assert_eq!(vec.capacity(), 20);
+    vec.shrink_to_fit();
+    assert_eq!(vec.capacity(), 10);
+    for (i, v) in vec.iter().enumerate() {
+        assert_eq!(i as i32, *v);
+    }
+
+    vec.drain(..);
+    vec.shrink_to_fit();
+    assert_eq!(vec.capacity(), 0);
+    assert_eq!(alloc.alloc_cnt(), 0);
+    vec.push(1);
+    assert_eq!(&*vec, &[1]);
+}
+
+#[test]
+fn test_shrink_zst() {
+    let mut vec = serial::Vec::<()>::new();
+    for _ in 0..10 {
+        vec.push(());
+    }
+    vec.shrink_to_fit();
+    vec.shrink_to(5);
+    assert_eq!(vec.len(), 10);
+    assert_eq!(vec.capacity(), usize::MAX);
+}
+
+#[test]
+fn test_from_iter_extend() {
+    let mut vec: serial::Vec<i32> = (0..5).collect();
+    assert_eq!(&*vec, &[0, 1, 2, 3, 4]);
+    vec.extend(5..8);
+    vec.extend(&[8, 9]);
+    assert_eq!(vec.len(), 10);
+    for (i, v) in vec.iter().enumerate() {
+        assert_eq!(i as i32, *v);
+    }
+}
+
+#[test]
+fn test_vec_macro() {
+    let empty: serial::Vec<i32> = serial::vec![];
+    assert!(empty.is_empty());
+    let vec = serial::vec![1, 2, 3];
+    assert_eq!(&*vec, &[1, 2, 3]);
+    let vec = serial::vec![String::from("a"); 3];
+    assert_eq!(vec.len(), 3);
+    assert!(vec.iter().all(|s| s == "a"));
+
+    let mock = utils::DropMock::new();
+    let vec = serial::vec![mock.clone(); 0];
+    assert!(vec.is_empty());
+    assert_eq!(mock.drop_cnt(), 1);
+}
+
+#[test]
+fn test_clone() {
+    let vec = serial::vec![String::from("a"), String::from("b")];
+    let mut other = vec.clone();
+    assert_eq!(vec, other);
+    other.push(String::from("c"));
+    assert_ne!(vec, other);
+
+    let ptr = other.as_ptr();
+    other.clone_from(&vec);
+    assert_eq!(vec, other);
+    assert_eq!(ptr, other.as_ptr());
+}
+
+#[test]
+fn test_traits() {
+    let vec = serial::vec![1, 2, 3];
+    assert_eq!(format!("{vec:?}"), "[1, 2, 3]");
+    assert_eq!(vec, [1, 2, 3]);
+    assert_eq!(vec, &[1, 2, 3][..]);
+    assert_eq!(vec, std::vec![1, 2, 3]);
+    assert!(vec < serial::vec![1, 2, 4]);
+    assert!(vec > serial::vec![1, 2]);
+    assert_eq!(vec.cmp(&serial::vec![1, 2, 3]), std::cmp::Ordering::Equal);
+
+    let mut set = std::collections::HashSet::new();
+    set.insert(vec.clone());
+    assert!(set.contains(&serial::vec![1, 2, 3]));
+}